@@ -0,0 +1,138 @@
+// Keyboard navigation and selection for `Combobox`. The listbox content is
+// swapped in by htmx, option ids are assigned here for `aria-activedescendant`.
+(function () {
+    function parts(element) {
+        var root = element.closest("[data-combobox]");
+        return {
+            root: root,
+            input: root.querySelector('[role="combobox"]'),
+            listbox: root.querySelector('[role="listbox"]'),
+            value: root.querySelector("[data-combobox-value]"),
+        };
+    }
+
+    function options(combobox) {
+        var all = combobox.listbox.querySelectorAll('[role="option"]');
+        all.forEach(function (option, index) {
+            option.id || (option.id = combobox.listbox.id + "-option-" + index);
+        });
+        return Array.prototype.slice.call(all).filter(function (option) {
+            return option.getAttribute("aria-disabled") !== "true";
+        });
+    }
+
+    function highlighted(combobox) {
+        return combobox.listbox.querySelector('[role="option"][data-highlighted]');
+    }
+
+    function highlight(combobox, option) {
+        var current = highlighted(combobox);
+        current !== null && current.removeAttribute("data-highlighted");
+        if (!option) {
+            combobox.input.removeAttribute("aria-activedescendant");
+            return;
+        }
+        option.setAttribute("data-highlighted", "");
+        combobox.input.setAttribute("aria-activedescendant", option.id);
+        option.scrollIntoView({ block: "nearest" });
+    }
+
+    function open(combobox) {
+        if (combobox.listbox.children.length === 0) {
+            return;
+        }
+        combobox.listbox.hidden = false;
+        combobox.input.setAttribute("aria-expanded", "true");
+    }
+
+    function close(combobox) {
+        combobox.listbox.hidden = true;
+        combobox.input.setAttribute("aria-expanded", "false");
+        highlight(combobox, null);
+    }
+
+    function choose(combobox, option) {
+        options(combobox).forEach(function (o) {
+            o.setAttribute("aria-selected", o === option ? "true" : "false");
+        });
+        combobox.input.value = option.dataset.label;
+        combobox.value.value = option.dataset.value;
+        combobox.value.dispatchEvent(new Event("change", { bubbles: true }));
+        close(combobox);
+    }
+
+    function move(combobox, offset) {
+        var all = options(combobox);
+        var index = all.indexOf(highlighted(combobox));
+        highlight(combobox, all[Math.min(Math.max(index + offset, 0), all.length - 1)]);
+    }
+
+    document.addEventListener("htmx:afterSwap", function (event) {
+        if (event.target.matches("[data-combobox] [role='listbox']")) {
+            var combobox = parts(event.target);
+            close(combobox);
+            open(combobox);
+        }
+    });
+
+    // The search input has no `name` so that it isn't part of the enclosing
+    // form, its text is added to the requests it sends here.
+    document.addEventListener("htmx:configRequest", function (event) {
+        var input = event.detail.elt;
+        if (input.matches("[data-combobox] [role='combobox']")) {
+            event.detail.parameters[input.dataset.comboboxSearchParam] = input.value;
+        }
+    });
+
+    document.addEventListener("input", function (event) {
+        if (event.target.matches("[data-combobox] [role='combobox']")) {
+            // The typed text no longer matches the chosen option.
+            parts(event.target).value.value = "";
+        }
+    });
+
+    document.addEventListener("click", function (event) {
+        document.querySelectorAll("[data-combobox]").forEach(function (root) {
+            if (!root.contains(event.target)) {
+                close(parts(root));
+            }
+        });
+
+        var option = event.target.closest("[data-combobox] [role='option']");
+        if (option !== null && option.getAttribute("aria-disabled") !== "true") {
+            choose(parts(option), option);
+        }
+    });
+
+    document.addEventListener("keydown", function (event) {
+        if (!event.target.matches("[data-combobox] [role='combobox']")) {
+            return;
+        }
+
+        var combobox = parts(event.target);
+        switch (event.key) {
+            case "ArrowDown":
+                combobox.listbox.hidden ? open(combobox) : move(combobox, 1);
+                break;
+            case "ArrowUp":
+                combobox.listbox.hidden ? open(combobox) : move(combobox, -1);
+                break;
+            case "Enter":
+                if (combobox.listbox.hidden || highlighted(combobox) === null) {
+                    return;
+                }
+                choose(combobox, highlighted(combobox));
+                break;
+            case "Escape":
+                if (combobox.listbox.hidden) {
+                    return;
+                }
+                close(combobox);
+                break;
+            default:
+                return;
+        }
+
+        event.preventDefault();
+    });
+})();
@@ -0,0 +1,177 @@
+// Filtering and keyboard navigation for `Command`, and the global shortcut
+// opening `CommandDialog`. The focus stays in the input, the highlighted item
+// is exposed through `aria-activedescendant`. Listeners are delegated to
+// `document` so commands swapped in by htmx just work.
+(function () {
+    var count = 0;
+
+    function parts(element) {
+        var root = element.closest("[data-command]");
+        return {
+            root: root,
+            input: root.querySelector('[role="combobox"]'),
+            list: root.querySelector('[role="listbox"]'),
+            empty: root.querySelector("[data-command-empty]"),
+        };
+    }
+
+    function items(command) {
+        return Array.prototype.slice.call(command.list.querySelectorAll('[role="option"]')).filter(function (item) {
+            return !item.hidden && item.getAttribute("aria-disabled") !== "true";
+        });
+    }
+
+    function highlighted(command) {
+        return command.list.querySelector('[role="option"][data-highlighted]');
+    }
+
+    function highlight(command, item) {
+        var current = highlighted(command);
+        if (current !== null) {
+            current.removeAttribute("data-highlighted");
+            current.setAttribute("aria-selected", "false");
+        }
+        if (!item) {
+            command.input.removeAttribute("aria-activedescendant");
+            return;
+        }
+        item.id || (item.id = command.list.id + "-item-" + ++count);
+        item.setAttribute("data-highlighted", "");
+        item.setAttribute("aria-selected", "true");
+        command.input.setAttribute("aria-activedescendant", item.id);
+        item.scrollIntoView({ block: "nearest" });
+    }
+
+    // Hide the static items not matching the input, the results fetched from
+    // the server are already filtered.
+    function filter(command) {
+        var query = command.input.value.trim().toLowerCase();
+        command.list.querySelectorAll("[data-command-group]").forEach(function (group) {
+            if (group.closest("[data-command-results]") !== null) {
+                return;
+            }
+            var visible = 0;
+            group.querySelectorAll('[role="option"]').forEach(function (item) {
+                var text = (item.textContent + " " + (item.dataset.keywords || "")).toLowerCase();
+                item.hidden = !text.includes(query);
+                item.hidden || visible++;
+            });
+            group.hidden = visible === 0;
+        });
+        // Separators only make sense when everything is shown.
+        command.list.querySelectorAll(':scope > [role="separator"]').forEach(function (separator) {
+            separator.hidden = query !== "";
+        });
+
+        var all = items(command);
+        command.empty.hidden = all.length > 0;
+        highlight(command, all[0]);
+    }
+
+    function move(command, offset) {
+        var all = items(command);
+        var index = all.indexOf(highlighted(command));
+        highlight(command, all[Math.min(Math.max(index + offset, 0), all.length - 1)]);
+    }
+
+    function openDialog(dialog) {
+        dialog.showModal();
+        parts(dialog.querySelector("[data-command]")).input.focus();
+    }
+
+    document.addEventListener("input", function (event) {
+        if (event.target.matches("[data-command] [role='combobox']")) {
+            filter(parts(event.target));
+        }
+    });
+
+    document.addEventListener("htmx:afterSwap", function (event) {
+        if (event.target.matches("[data-command] [data-command-results]")) {
+            filter(parts(event.target));
+        }
+    });
+
+    document.addEventListener("mousemove", function (event) {
+        var item = event.target.closest("[data-command] [role='option']");
+        if (item !== null && item.getAttribute("aria-disabled") !== "true" && !item.hasAttribute("data-highlighted")) {
+            highlight(parts(item), item);
+        }
+    });
+
+    document.addEventListener("click", function (event) {
+        var item = event.target.closest("[data-command] [role='option']");
+        if (item === null || item.getAttribute("aria-disabled") === "true") {
+            return;
+        }
+        var dialog = item.closest("dialog[data-command-dialog]");
+        dialog !== null && dialog.close();
+    });
+
+    document.addEventListener("keydown", function (event) {
+        if ((event.metaKey || event.ctrlKey) && !event.altKey && !event.shiftKey && event.key.length === 1) {
+            var dialog = document.querySelector(
+                'dialog[data-command-dialog="' + event.key.toLowerCase() + '"]'
+            );
+            if (dialog !== null) {
+                event.preventDefault();
+                dialog.open ? dialog.close() : openDialog(dialog);
+                return;
+            }
+        }
+
+        if (!event.target.matches("[data-command] [role='combobox']")) {
+            return;
+        }
+
+        var command = parts(event.target);
+        switch (event.key) {
+            case "ArrowDown":
+                move(command, 1);
+                break;
+            case "ArrowUp":
+                move(command, -1);
+                break;
+            case "Home":
+                highlight(command, items(command)[0]);
+                break;
+            case "End":
+                highlight(command, items(command).slice(-1)[0]);
+                break;
+            case "Enter":
+                if (highlighted(command) === null) {
+                    return;
+                }
+                // Runs the action of the item and closes the `CommandDialog`.
+                highlighted(command).click();
+                break;
+            default:
+                return;
+        }
+
+        event.preventDefault();
+    });
+
+    document.addEventListener("focusin", function (event) {
+        if (event.target.matches("[data-command] [role='combobox']")) {
+            var command = parts(event.target);
+            highlighted(command) === null && filter(command);
+        }
+    });
+
+    // Start from a fresh search every time a `CommandDialog` is opened.
+    document.addEventListener(
+        "close",
+        function (event) {
+            var dialog = event.target;
+            if (!dialog.matches("dialog[data-command-dialog]")) {
+                return;
+            }
+            var command = parts(dialog.querySelector("[data-command]"));
+            var results = command.list.querySelector("[data-command-results]");
+            results !== null && (results.innerHTML = "");
+            command.input.value = "";
+            filter(command);
+        },
+        true
+    );
+})();
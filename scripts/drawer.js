@@ -0,0 +1,152 @@
+// Drag and swipe-to-dismiss for `Drawer`. The drawer is moved with the CSS
+// `translate` property (so it composes with the opening animation) between
+// its snap points, and closed when dragged far enough or flicked down from the
+// lowest one. Listeners are delegated to `document` so drawers swapped in by
+// htmx just work.
+(function () {
+    var FLICK_VELOCITY = 0.5; // px per ms
+    var TRANSITION = "translate 0.3s cubic-bezier(0.32, 0.72, 0, 1)";
+    var drag = null;
+
+    function snapPoints(drawer) {
+        var points = drawer.dataset.drawerSnapPoints;
+        return points
+            ? points.split(",").map(Number).sort(function (a, b) {
+                  return a - b;
+              })
+            : [];
+    }
+
+    // Offsets from the fully open position the drawer rests at, largest (the
+    // lowest snap point) first.
+    function layout(drawer) {
+        var points = snapPoints(drawer);
+        if (points.length === 0) {
+            return { height: drawer.offsetHeight, offsets: [0] };
+        }
+        var viewport = window.innerHeight;
+        var highest = points[points.length - 1];
+        return {
+            height: highest * viewport,
+            offsets: points.map(function (point) {
+                return (highest - point) * viewport;
+            }),
+        };
+    }
+
+    function moveTo(drawer, offset, animate) {
+        drawer.style.transition = animate ? TRANSITION : "none";
+        drawer.style.translate = "0 " + offset + "px";
+        drawer.dataset.drawerOffset = offset;
+    }
+
+    function dismiss(drawer) {
+        moveTo(drawer, layout(drawer).height, true);
+        setTimeout(function () {
+            drawer.close();
+        }, 300);
+    }
+
+    function reset(drawer) {
+        drawer.style.transition = "";
+        drawer.style.translate = "";
+        delete drawer.dataset.drawerOffset;
+    }
+
+    new MutationObserver(function (mutations) {
+        mutations.forEach(function (mutation) {
+            var drawer = mutation.target;
+            if (!drawer.matches("[data-drawer]")) {
+                return;
+            }
+            if (!drawer.open) {
+                reset(drawer);
+                return;
+            }
+
+            var points = snapPoints(drawer);
+            if (points.length > 0) {
+                drawer.style.height = points[points.length - 1] * 100 + "dvh";
+                drawer.style.maxHeight = "none";
+                moveTo(drawer, layout(drawer).offsets[0], false);
+            }
+        });
+    }).observe(document.documentElement, {
+        attributes: true,
+        attributeFilter: ["open"],
+        subtree: true,
+    });
+
+    document.addEventListener("pointerdown", function (event) {
+        var handle = event.target.closest("[data-drawer] [data-drawer-handle]");
+        if (handle === null || event.button !== 0) {
+            return;
+        }
+
+        var drawer = handle.closest("[data-drawer]");
+        handle.setPointerCapture(event.pointerId);
+        drag = {
+            drawer: drawer,
+            startY: event.clientY,
+            startOffset: Number(drawer.dataset.drawerOffset || 0),
+            lastY: event.clientY,
+            lastTime: event.timeStamp,
+            velocity: 0,
+        };
+    });
+
+    document.addEventListener("pointermove", function (event) {
+        if (drag === null) {
+            return;
+        }
+
+        var elapsed = event.timeStamp - drag.lastTime;
+        if (elapsed > 0) {
+            drag.velocity = (event.clientY - drag.lastY) / elapsed;
+        }
+        drag.lastY = event.clientY;
+        drag.lastTime = event.timeStamp;
+
+        // Resist dragging above the highest snap point.
+        var offset = drag.startOffset + event.clientY - drag.startY;
+        moveTo(drag.drawer, offset < 0 ? offset / 4 : offset, false);
+    });
+
+    function release() {
+        if (drag === null) {
+            return;
+        }
+
+        var drawer = drag.drawer;
+        var velocity = drag.velocity;
+        drag = null;
+
+        var current = Number(drawer.dataset.drawerOffset || 0);
+        var box = layout(drawer);
+        var offsets = box.offsets;
+        var lowest = offsets[0];
+
+        if (velocity > FLICK_VELOCITY) {
+            // Flicked down: the next lower snap point, or closed from the lowest one.
+            var lower = offsets.filter(function (offset) {
+                return offset > current;
+            });
+            lower.length > 0 ? moveTo(drawer, lower[lower.length - 1], true) : dismiss(drawer);
+        } else if (velocity < -FLICK_VELOCITY) {
+            var higher = offsets.filter(function (offset) {
+                return offset < current;
+            });
+            moveTo(drawer, higher.length > 0 ? higher[0] : offsets[offsets.length - 1], true);
+        } else if (current > lowest + (box.height - lowest) / 2) {
+            dismiss(drawer);
+        } else {
+            var nearest = offsets.reduce(function (best, offset) {
+                return Math.abs(offset - current) < Math.abs(best - current) ? offset : best;
+            });
+            moveTo(drawer, nearest, true);
+        }
+    }
+
+    document.addEventListener("pointerup", release);
+    document.addEventListener("pointercancel", release);
+})();
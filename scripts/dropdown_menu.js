@@ -0,0 +1,263 @@
+// Menu button pattern for `DropdownMenu`. The focus moves through the items
+// of the open menu, submenus open with ArrowRight or on hover. A menu and the
+// element opening it are linked through `aria-controls`. Listeners are
+// delegated to `document` so menus swapped in by htmx just work.
+(function () {
+    var typeahead = "";
+    var typeaheadTimeout = null;
+
+    // Enabled items of `menu`, without the ones of its submenus.
+    function items(menu) {
+        return Array.prototype.slice
+            .call(menu.querySelectorAll("[role^='menuitem']"))
+            .filter(function (item) {
+                return (
+                    item.closest("[role='menu']") === menu &&
+                    item.getAttribute("aria-disabled") !== "true"
+                );
+            });
+    }
+
+    function opener(menu) {
+        return document.querySelector('[aria-controls="' + CSS.escape(menu.id) + '"]');
+    }
+
+    function controlled(opener) {
+        return document.getElementById(opener.getAttribute("aria-controls"));
+    }
+
+    // Element opening a root menu, possibly wrapped (e.g. by a tooltip).
+    function trigger(element) {
+        var button = element.closest("[data-dropdown-menu] [aria-haspopup='menu']");
+        return button !== null && button.closest("[role='menu']") === null ? button : null;
+    }
+
+    function rootMenu(root) {
+        return root.querySelector(":scope > [role='menu']");
+    }
+
+    function open(menu, focus) {
+        menu.hidden = false;
+        opener(menu).setAttribute("aria-expanded", "true");
+
+        var all = items(menu);
+        if (focus === "first" && all.length > 0) {
+            all[0].focus();
+        } else if (focus === "last" && all.length > 0) {
+            all[all.length - 1].focus();
+        } else if (focus !== "none") {
+            menu.focus();
+        }
+    }
+
+    function close(menu) {
+        closeSubmenus(menu);
+        menu.hidden = true;
+        opener(menu).setAttribute("aria-expanded", "false");
+    }
+
+    function closeSubmenus(menu, except) {
+        menu.querySelectorAll("[role='menu']").forEach(function (sub) {
+            if (sub !== except && !sub.hidden && !sub.contains(except || null)) {
+                close(sub);
+            }
+        });
+    }
+
+    function closeRoot(root, focusTrigger) {
+        var menu = rootMenu(root);
+        if (menu.hidden) {
+            return;
+        }
+        close(menu);
+        focusTrigger && opener(menu).focus();
+    }
+
+    function move(menu, offset) {
+        var all = items(menu);
+        var index = all.indexOf(document.activeElement);
+        if (index === -1) {
+            index = offset > 0 ? -1 : all.length;
+        }
+        var next = all[Math.min(Math.max(index + offset, 0), all.length - 1)];
+        next !== undefined && next.focus();
+    }
+
+    function search(menu, key) {
+        clearTimeout(typeaheadTimeout);
+        typeaheadTimeout = setTimeout(function () {
+            typeahead = "";
+        }, 500);
+        typeahead += key.toLowerCase();
+
+        var all = items(menu);
+        var start = all.indexOf(document.activeElement);
+        // Cycle through items with the same first letter when it is repeated.
+        var ordered = all.slice(start + 1).concat(all.slice(0, start + 1));
+        var query = typeahead.split("").every(function (c) {
+            return c === typeahead[0];
+        })
+            ? typeahead[0]
+            : typeahead;
+        var match = ordered.find(function (item) {
+            return item.textContent.trim().toLowerCase().startsWith(query);
+        });
+        match !== undefined && match.focus();
+    }
+
+    // Update checkbox and radio items before htmx reads them on click.
+    document.addEventListener(
+        "click",
+        function (event) {
+            var item = event.target.closest(
+                "[data-dropdown-menu] [role='menuitemcheckbox'], [data-dropdown-menu] [role='menuitemradio']"
+            );
+            if (item === null || item.getAttribute("aria-disabled") === "true") {
+                return;
+            }
+
+            if (item.getAttribute("role") === "menuitemcheckbox") {
+                var checked = item.getAttribute("aria-checked") === "true";
+                item.setAttribute("aria-checked", checked ? "false" : "true");
+            } else {
+                item.parentElement.querySelectorAll("[role='menuitemradio']").forEach(function (radio) {
+                    radio.setAttribute("aria-checked", radio === item ? "true" : "false");
+                });
+            }
+        },
+        true
+    );
+
+    document.addEventListener("click", function (event) {
+        document.querySelectorAll("[data-dropdown-menu]").forEach(function (root) {
+            if (!root.contains(event.target)) {
+                closeRoot(root, false);
+            }
+        });
+
+        var button = trigger(event.target);
+        if (button !== null) {
+            var menu = controlled(button);
+            menu.hidden ? open(menu) : close(menu);
+            return;
+        }
+
+        var item = event.target.closest("[data-dropdown-menu] [role^='menuitem']");
+        if (item === null) {
+            return;
+        }
+        if (item.getAttribute("aria-disabled") === "true") {
+            event.preventDefault();
+        } else if (item.getAttribute("aria-haspopup") === "menu") {
+            open(controlled(item), "first");
+        } else {
+            closeRoot(item.closest("[data-dropdown-menu]"), true);
+        }
+    });
+
+    document.addEventListener("mouseover", function (event) {
+        var item = event.target.closest("[data-dropdown-menu] [role='menu'] [role^='menuitem']");
+        if (item === null || item.getAttribute("aria-disabled") === "true") {
+            return;
+        }
+
+        var menu = item.closest("[role='menu']");
+        var sub = item.getAttribute("aria-haspopup") === "menu" ? controlled(item) : null;
+        closeSubmenus(menu, sub);
+        sub !== null && sub.hidden && open(sub, "none");
+        item.focus({ preventScroll: true });
+    });
+
+    document.addEventListener("keydown", function (event) {
+        var root = event.target.closest("[data-dropdown-menu]");
+        if (root === null) {
+            return;
+        }
+
+        if (trigger(event.target) === event.target) {
+            switch (event.key) {
+                case "ArrowDown":
+                case "Enter":
+                case " ":
+                    open(controlled(event.target), "first");
+                    break;
+                case "ArrowUp":
+                    open(controlled(event.target), "last");
+                    break;
+                default:
+                    return;
+            }
+            event.preventDefault();
+            return;
+        }
+
+        var menu = event.target.closest("[role='menu']");
+        if (menu === null) {
+            return;
+        }
+        var item = event.target.closest("[role^='menuitem']");
+        var isSubmenu = menu !== rootMenu(root);
+
+        switch (event.key) {
+            case "ArrowDown":
+                move(menu, 1);
+                break;
+            case "ArrowUp":
+                move(menu, -1);
+                break;
+            case "Home":
+                move(menu, -items(menu).length);
+                break;
+            case "End":
+                move(menu, items(menu).length);
+                break;
+            case "ArrowRight":
+                if (item === null || item.getAttribute("aria-haspopup") !== "menu") {
+                    return;
+                }
+                open(controlled(item), "first");
+                break;
+            case "ArrowLeft":
+                if (!isSubmenu) {
+                    return;
+                }
+                close(menu);
+                opener(menu).focus();
+                break;
+            case "Escape":
+                if (isSubmenu) {
+                    close(menu);
+                    opener(menu).focus();
+                } else {
+                    closeRoot(root, true);
+                }
+                break;
+            case "Enter":
+            case " ":
+                item !== null && item.click();
+                break;
+            case "Tab":
+                closeRoot(root, false);
+                return;
+            default:
+                if (event.key.length !== 1 || event.ctrlKey || event.metaKey || event.altKey) {
+                    return;
+                }
+                search(menu, event.key);
+        }
+
+        event.preventDefault();
+    });
+
+    // Send the state of checkbox and radio items with their `hx-post`.
+    document.addEventListener("htmx:configRequest", function (event) {
+        var item = event.detail.elt;
+        if (!item.matches("[role='menuitemcheckbox'][data-name], [role='menuitemradio'][data-name]")) {
+            return;
+        }
+        event.detail.parameters[item.dataset.name] =
+            item.getAttribute("role") === "menuitemcheckbox"
+                ? item.getAttribute("aria-checked")
+                : item.dataset.value;
+    });
+})();
@@ -0,0 +1,114 @@
+// Positioning of floating content (`Popover`, `DropdownMenu`, `Combobox`,
+// `Tooltip`…). Elements with `data-floating` are placed with `position: fixed`
+// next to their anchor when they are shown, through the `hidden` attribute or
+// the popover API, and kept there on scroll and resize.
+//
+//   data-floating-side    top | right | bottom (default) | left
+//   data-floating-align   start | center (default) | end
+//   data-floating-offset  distance from the anchor in pixels
+//   data-floating-anchor  id of the anchor, defaults to the previous sibling
+//
+// The side and alignment actually used are written to `data-side` and
+// `data-align`, and the anchor width to the `--floating-anchor-width` property.
+(function () {
+    var PADDING = 8;
+    var OPPOSITE = { top: "bottom", bottom: "top", left: "right", right: "left" };
+
+    function anchorOf(floating) {
+        var id = floating.dataset.floatingAnchor;
+        return id ? document.getElementById(id) : floating.previousElementSibling;
+    }
+
+    function isShown(floating) {
+        return floating.getClientRects().length > 0;
+    }
+
+    function clamp(value, min, max) {
+        return Math.max(min, Math.min(value, max));
+    }
+
+    function place(floating) {
+        var anchor = anchorOf(floating);
+        if (anchor === null || !isShown(floating)) {
+            return;
+        }
+
+        var a = anchor.getBoundingClientRect();
+        floating.style.setProperty("--floating-anchor-width", a.width + "px");
+        floating.style.position = "fixed";
+        floating.style.margin = "0";
+        floating.style.right = "auto";
+        floating.style.bottom = "auto";
+
+        var f = floating.getBoundingClientRect();
+        var side = floating.dataset.floatingSide || "bottom";
+        var align = floating.dataset.floatingAlign || "center";
+        var offset = Number(floating.dataset.floatingOffset || 0);
+        var width = document.documentElement.clientWidth;
+        var height = document.documentElement.clientHeight;
+
+        // Flip to the opposite side when it doesn't fit and there is more room there.
+        var vertical = side === "top" || side === "bottom";
+        var room = { top: a.top, bottom: height - a.bottom, left: a.left, right: width - a.right };
+        var size = (vertical ? f.height : f.width) + offset + PADDING;
+        if (room[side] < size && room[OPPOSITE[side]] > room[side]) {
+            side = OPPOSITE[side];
+        }
+
+        var x, y;
+        if (vertical) {
+            y = side === "bottom" ? a.bottom + offset : a.top - offset - f.height;
+            x =
+                align === "start"
+                    ? a.left
+                    : align === "end"
+                      ? a.right - f.width
+                      : a.left + (a.width - f.width) / 2;
+        } else {
+            x = side === "right" ? a.right + offset : a.left - offset - f.width;
+            y =
+                align === "start"
+                    ? a.top
+                    : align === "end"
+                      ? a.bottom - f.height
+                      : a.top + (a.height - f.height) / 2;
+        }
+
+        floating.style.left = clamp(x, PADDING, width - f.width - PADDING) + "px";
+        floating.style.top = clamp(y, PADDING, height - f.height - PADDING) + "px";
+        floating.dataset.side = side;
+        floating.dataset.align = align;
+    }
+
+    function update() {
+        document.querySelectorAll("[data-floating]").forEach(place);
+    }
+
+    new MutationObserver(function (mutations) {
+        mutations.forEach(function (mutation) {
+            if (mutation.target.matches("[data-floating]")) {
+                place(mutation.target);
+            }
+        });
+    }).observe(document.documentElement, {
+        attributes: true,
+        attributeFilter: ["hidden"],
+        subtree: true,
+    });
+
+    // `toggle` doesn't bubble, it is caught on its way down.
+    document.addEventListener(
+        "toggle",
+        function (event) {
+            if (event.newState === "open" && event.target.matches("[data-floating]")) {
+                place(event.target);
+            }
+        },
+        true
+    );
+
+    // Content loaded into a floating element may change its size.
+    document.addEventListener("htmx:afterSettle", update);
+    window.addEventListener("scroll", update, true);
+    window.addEventListener("resize", update);
+})();
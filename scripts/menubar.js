@@ -0,0 +1,102 @@
+// Keyboard and pointer behaviour of `Menubar` on top of the one of
+// `DropdownMenu`: the focus roves between the top-level items, and moving
+// left or right while a menu is open opens the adjacent one. Listeners are
+// delegated to `document` so menubars swapped in by htmx just work.
+(function () {
+    var TOP = "[data-menubar] > [role='menuitem'], [data-menubar] > [data-dropdown-menu] > [role='menuitem']";
+
+    function tops(menubar) {
+        return Array.prototype.slice.call(menubar.querySelectorAll(TOP));
+    }
+
+    function topOf(element) {
+        var root = element.closest("[data-menubar] > [data-dropdown-menu]");
+        return root !== null ? root.firstElementChild : element.closest(TOP);
+    }
+
+    function adjacent(top, offset) {
+        var all = tops(top.closest("[data-menubar]"));
+        return all[(all.indexOf(top) + offset + all.length) % all.length];
+    }
+
+    // Move from the open menu of `top` to the menu (or link) of `next`.
+    function switchTo(top, next) {
+        if (top.getAttribute("aria-expanded") === "true") {
+            top.click();
+        }
+        next.focus();
+        if (next.getAttribute("aria-haspopup") === "menu") {
+            next.click();
+            var first = next.nextElementSibling.querySelector("[role^='menuitem']:not([aria-disabled='true'])");
+            first !== null && first.focus();
+        }
+    }
+
+    document.addEventListener("keydown", function (event) {
+        if (event.target.closest("[data-menubar]") === null) {
+            return;
+        }
+        var top = topOf(event.target);
+
+        if (event.target === top) {
+            var all = tops(top.closest("[data-menubar]"));
+            switch (event.key) {
+                case "ArrowRight":
+                    adjacent(top, 1).focus();
+                    break;
+                case "ArrowLeft":
+                    adjacent(top, -1).focus();
+                    break;
+                case "Home":
+                    all[0].focus();
+                    break;
+                case "End":
+                    all[all.length - 1].focus();
+                    break;
+                default:
+                    return;
+            }
+            event.preventDefault();
+            return;
+        }
+
+        // In an open menu, only handle what `DropdownMenu` leaves alone.
+        var menu = event.target.closest("[role='menu']");
+        var item = event.target.closest("[role^='menuitem']");
+        if (event.key === "ArrowRight" && (item === null || item.getAttribute("aria-haspopup") !== "menu")) {
+            switchTo(top, adjacent(top, 1));
+        } else if (event.key === "ArrowLeft" && menu === top.nextElementSibling) {
+            switchTo(top, adjacent(top, -1));
+        } else {
+            return;
+        }
+        event.preventDefault();
+    });
+
+    // Hovering another menu while one is open switches to it.
+    document.addEventListener("mouseover", function (event) {
+        var top = event.target.closest(TOP);
+        if (top === null || top.getAttribute("aria-expanded") === "true") {
+            return;
+        }
+        var open = tops(top.closest("[data-menubar]")).find(function (other) {
+            return other.getAttribute("aria-expanded") === "true";
+        });
+        if (open !== undefined) {
+            open.click();
+            top.focus();
+            top.getAttribute("aria-haspopup") === "menu" && top.click();
+        }
+    });
+
+    // Keep the last focused top-level item as the one reached with Tab.
+    document.addEventListener("focusin", function (event) {
+        if (event.target.closest("[data-menubar]") === null) {
+            return;
+        }
+        var top = topOf(event.target);
+        tops(top.closest("[data-menubar]")).forEach(function (other) {
+            other.setAttribute("tabindex", other === top ? "0" : "-1");
+        });
+    });
+})();
@@ -0,0 +1,52 @@
+// Panels of `NavigationMenu` (disclosure pattern): a trigger toggles the
+// panel right after it, at most one panel is open per menu. Listeners are
+// delegated to `document` so menus swapped in by htmx just work.
+(function () {
+    function close(trigger) {
+        trigger.setAttribute("aria-expanded", "false");
+        trigger.nextElementSibling.hidden = true;
+    }
+
+    function closeAll(nav, except) {
+        nav.querySelectorAll("[aria-controls][aria-expanded='true']").forEach(function (trigger) {
+            trigger !== except && close(trigger);
+        });
+    }
+
+    document.addEventListener("click", function (event) {
+        document.querySelectorAll("[data-navigation-menu]").forEach(function (nav) {
+            nav.contains(event.target) || closeAll(nav);
+        });
+
+        var trigger = event.target.closest("[data-navigation-menu] button[aria-controls]");
+        if (trigger === null) {
+            return;
+        }
+        closeAll(trigger.closest("[data-navigation-menu]"), trigger);
+        if (trigger.getAttribute("aria-expanded") === "true") {
+            close(trigger);
+        } else {
+            trigger.setAttribute("aria-expanded", "true");
+            trigger.nextElementSibling.hidden = false;
+        }
+    });
+
+    document.addEventListener("keydown", function (event) {
+        var nav = event.target.closest("[data-navigation-menu]");
+        if (nav === null || event.key !== "Escape") {
+            return;
+        }
+        var trigger = nav.querySelector("[aria-controls][aria-expanded='true']");
+        if (trigger !== null) {
+            close(trigger);
+            trigger.focus();
+        }
+    });
+
+    document.addEventListener("focusout", function (event) {
+        var nav = event.target.closest("[data-navigation-menu]");
+        if (nav !== null && !nav.contains(event.relatedTarget)) {
+            closeAll(nav);
+        }
+    });
+})();
@@ -0,0 +1,169 @@
+// Listbox mode of `Select` (WAI-ARIA select-only combobox pattern).
+// Focus stays on the trigger, the highlighted option is exposed through
+// `aria-activedescendant`. Listeners are delegated to `document` so selects
+// swapped in by htmx just work.
+(function () {
+    var typeahead = "";
+    var typeaheadTimeout = null;
+
+    function parts(element) {
+        var root = element.closest("[data-select]");
+        return {
+            root: root,
+            trigger: root.querySelector('[role="combobox"]'),
+            listbox: root.querySelector('[role="listbox"]'),
+            input: root.querySelector("[data-select-input]"),
+        };
+    }
+
+    function options(select) {
+        return Array.prototype.slice.call(
+            select.listbox.querySelectorAll('[role="option"]:not([aria-disabled="true"])')
+        );
+    }
+
+    function highlighted(select) {
+        return select.listbox.querySelector('[role="option"][data-highlighted]');
+    }
+
+    function highlight(select, option) {
+        var current = highlighted(select);
+        current !== null && current.removeAttribute("data-highlighted");
+        if (!option) {
+            return;
+        }
+        option.setAttribute("data-highlighted", "");
+        select.trigger.setAttribute("aria-activedescendant", option.id);
+        option.scrollIntoView({ block: "nearest" });
+    }
+
+    function isOpen(select) {
+        return !select.listbox.hidden;
+    }
+
+    function open(select) {
+        select.listbox.hidden = false;
+        select.trigger.setAttribute("aria-expanded", "true");
+        highlight(
+            select,
+            select.listbox.querySelector('[role="option"][aria-selected="true"]') || options(select)[0]
+        );
+    }
+
+    function close(select) {
+        select.listbox.hidden = true;
+        select.trigger.setAttribute("aria-expanded", "false");
+        select.trigger.removeAttribute("aria-activedescendant");
+        highlight(select, null);
+    }
+
+    function choose(select, option) {
+        select.listbox.querySelectorAll('[role="option"]').forEach(function (o) {
+            o.setAttribute("aria-selected", o === option ? "true" : "false");
+        });
+
+        var label = select.trigger.querySelector("[data-select-value]");
+        label.textContent = option.textContent.trim();
+        label.removeAttribute("data-placeholder");
+
+        select.input.value = option.dataset.value;
+        select.input.dispatchEvent(new Event("change", { bubbles: true }));
+
+        close(select);
+        select.trigger.focus();
+    }
+
+    function move(select, offset) {
+        var all = options(select);
+        var index = all.indexOf(highlighted(select));
+        highlight(select, all[Math.min(Math.max(index + offset, 0), all.length - 1)]);
+    }
+
+    function search(select, key) {
+        clearTimeout(typeaheadTimeout);
+        typeaheadTimeout = setTimeout(function () {
+            typeahead = "";
+        }, 500);
+        typeahead += key.toLowerCase();
+
+        var all = options(select);
+        var start = all.indexOf(highlighted(select));
+        // Cycle through options with the same first letter when it is repeated.
+        var ordered = all.slice(start + 1).concat(all.slice(0, start + 1));
+        var query = typeahead.split("").every(function (c) {
+            return c === typeahead[0];
+        })
+            ? typeahead[0]
+            : typeahead;
+        var match = ordered.find(function (option) {
+            return option.textContent.trim().toLowerCase().startsWith(query);
+        });
+        match !== undefined && highlight(select, match);
+    }
+
+    document.addEventListener("click", function (event) {
+        document.querySelectorAll("[data-select]").forEach(function (root) {
+            var select = parts(root);
+            if (isOpen(select) && !root.contains(event.target)) {
+                close(select);
+            }
+        });
+
+        var trigger = event.target.closest("[data-select] [role='combobox']");
+        if (trigger !== null) {
+            var select = parts(trigger);
+            isOpen(select) ? close(select) : open(select);
+            return;
+        }
+
+        var option = event.target.closest("[data-select] [role='option']");
+        if (option !== null && option.getAttribute("aria-disabled") !== "true") {
+            choose(parts(option), option);
+        }
+    });
+
+    document.addEventListener("keydown", function (event) {
+        var trigger = event.target.closest("[data-select] [role='combobox']");
+        if (trigger === null) {
+            return;
+        }
+
+        var select = parts(trigger);
+        switch (event.key) {
+            case "ArrowDown":
+                isOpen(select) ? move(select, 1) : open(select);
+                break;
+            case "ArrowUp":
+                isOpen(select) ? move(select, -1) : open(select);
+                break;
+            case "Home":
+                isOpen(select) && highlight(select, options(select)[0]);
+                break;
+            case "End":
+                isOpen(select) && highlight(select, options(select).slice(-1)[0]);
+                break;
+            case "Enter":
+            case " ":
+                if (isOpen(select) && highlighted(select) !== null) {
+                    choose(select, highlighted(select));
+                } else if (!isOpen(select)) {
+                    open(select);
+                }
+                break;
+            case "Escape":
+                isOpen(select) && close(select);
+                break;
+            case "Tab":
+                isOpen(select) && close(select);
+                return;
+            default:
+                if (event.key.length !== 1 || event.ctrlKey || event.metaKey || event.altKey) {
+                    return;
+                }
+                isOpen(select) || open(select);
+                search(select, event.key);
+        }
+
+        event.preventDefault();
+    });
+})();
@@ -0,0 +1,64 @@
+// Keyboard and click handling for `Tabs` (WAI-ARIA tabs pattern).
+// Listeners are delegated to `document` so tabs swapped in by htmx just work.
+(function () {
+    function activate(tab, focus) {
+        var tablist = tab.closest('[role="tablist"]');
+        tablist.querySelectorAll('[role="tab"]').forEach(function (t) {
+            var selected = t === tab;
+            t.setAttribute("aria-selected", selected ? "true" : "false");
+            t.setAttribute("tabindex", selected ? "0" : "-1");
+            t.dataset.state = selected ? "active" : "inactive";
+
+            var panel = document.getElementById(t.getAttribute("aria-controls"));
+            if (panel !== null) {
+                panel.hidden = !selected;
+                panel.dataset.state = t.dataset.state;
+            }
+        });
+
+        // Lazy panels are loaded by htmx on their first `tabs:activate`.
+        var panel = document.getElementById(tab.getAttribute("aria-controls"));
+        panel !== null && panel.dispatchEvent(new Event("tabs:activate"));
+
+        focus && tab.focus();
+    }
+
+    document.addEventListener("click", function (event) {
+        var tab = event.target.closest("[data-tabs] [role='tab']");
+        if (tab !== null && !tab.disabled) {
+            activate(tab, false);
+        }
+    });
+
+    document.addEventListener("keydown", function (event) {
+        var tab = event.target.closest("[data-tabs] [role='tab']");
+        if (tab === null) {
+            return;
+        }
+
+        var tabs = Array.prototype.slice.call(
+            tab.closest('[role="tablist"]').querySelectorAll('[role="tab"]:not([disabled])')
+        );
+        var index = tabs.indexOf(tab);
+        var next;
+        switch (event.key) {
+            case "ArrowRight":
+                next = tabs[(index + 1) % tabs.length];
+                break;
+            case "ArrowLeft":
+                next = tabs[(index - 1 + tabs.length) % tabs.length];
+                break;
+            case "Home":
+                next = tabs[0];
+                break;
+            case "End":
+                next = tabs[tabs.length - 1];
+                break;
+            default:
+                return;
+        }
+
+        event.preventDefault();
+        activate(next, true);
+    });
+})();
@@ -0,0 +1,38 @@
+// Render the toasts sent by the server in the `HX-Trigger` response header
+// (`{"toast": {"toasts": [...]}}`) using the templates of the `Toaster`.
+(function () {
+    document.addEventListener("toast", function (event) {
+        var toaster = document.querySelector("[data-toaster]");
+        if (toaster === null) {
+            return;
+        }
+
+        (event.detail.toasts || []).forEach(function (toast) {
+            var template = document.querySelector(
+                'template[data-toast-template="' + toast.variant + '"]'
+            );
+            if (template === null) {
+                return;
+            }
+
+            var li = template.content.firstElementChild.cloneNode(true);
+            var fill = function (selector, text) {
+                var element = li.querySelector(selector);
+                element.textContent = text || "";
+                element.hidden = !text;
+                return element;
+            };
+
+            fill("[data-toast-title]", toast.title);
+            fill("[data-toast-description]", toast.description);
+            var action = fill("[data-toast-action]", toast.action && toast.action.label);
+            toast.action && action.setAttribute("hx-post", toast.action.hx_post);
+            toast.duration > 0 &&
+                li.setAttribute("_", "init wait " + toast.duration + "ms then remove me");
+
+            toaster.appendChild(li);
+            window.htmx && htmx.process(li);
+            window._hyperscript && _hyperscript.processNode(li);
+        });
+    });
+})();
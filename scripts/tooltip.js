@@ -0,0 +1,91 @@
+// Show and hide `Tooltip`s on hover (after a delay) and focus. A tooltip is
+// the element right after its trigger in a `[data-tooltip]` wrapper. Moving
+// from one trigger to another shortly after a tooltip was hidden skips the
+// delay. Listeners are delegated to `document` so tooltips swapped in by htmx
+// just work.
+(function () {
+    var SKIP_DELAY = 300;
+    var count = 0;
+    var showTimeout = null;
+    var lastHidden = 0;
+
+    function parts(element) {
+        var root = element.closest("[data-tooltip]");
+        return {
+            root: root,
+            trigger: root.firstElementChild,
+            tooltip: root.querySelector(":scope > [role='tooltip']"),
+        };
+    }
+
+    function show(tooltip) {
+        clearTimeout(showTimeout);
+        document.querySelectorAll("[data-tooltip] > [role='tooltip']:not([hidden])").forEach(function (other) {
+            other !== tooltip.tooltip && hide(parts(other));
+        });
+
+        tooltip.tooltip.id || (tooltip.tooltip.id = "tooltip-" + ++count);
+        // A tooltip repeating the label of an icon button doesn't describe it further.
+        if (tooltip.trigger.getAttribute("aria-label") !== tooltip.tooltip.textContent.trim()) {
+            tooltip.trigger.setAttribute("aria-describedby", tooltip.tooltip.id);
+        }
+        tooltip.tooltip.hidden = false;
+    }
+
+    function hide(tooltip) {
+        clearTimeout(showTimeout);
+        if (tooltip.tooltip.hidden) {
+            return;
+        }
+        tooltip.tooltip.hidden = true;
+        tooltip.trigger.removeAttribute("aria-describedby");
+        lastHidden = Date.now();
+    }
+
+    document.addEventListener("mouseover", function (event) {
+        var root = event.target.closest("[data-tooltip]");
+        if (root === null || root.contains(event.relatedTarget)) {
+            return;
+        }
+
+        var tooltip = parts(root);
+        var delay = Date.now() - lastHidden < SKIP_DELAY ? 0 : Number(root.dataset.tooltipDelay || 0);
+        clearTimeout(showTimeout);
+        showTimeout = setTimeout(function () {
+            show(tooltip);
+        }, delay);
+    });
+
+    document.addEventListener("mouseout", function (event) {
+        var root = event.target.closest("[data-tooltip]");
+        if (root !== null && !root.contains(event.relatedTarget) && root.firstElementChild !== document.activeElement) {
+            hide(parts(root));
+        }
+    });
+
+    document.addEventListener("focusin", function (event) {
+        var root = event.target.closest("[data-tooltip]");
+        if (root !== null && event.target === root.firstElementChild && event.target.matches(":focus-visible")) {
+            show(parts(root));
+        }
+    });
+
+    document.addEventListener("focusout", function (event) {
+        var root = event.target.closest("[data-tooltip]");
+        root !== null && hide(parts(root));
+    });
+
+    document.addEventListener("keydown", function (event) {
+        if (event.key === "Escape") {
+            document.querySelectorAll("[data-tooltip] > [role='tooltip']:not([hidden])").forEach(function (tooltip) {
+                hide(parts(tooltip));
+            });
+        }
+    });
+
+    // Clicking the trigger (e.g. a button running an action) dismisses its tooltip.
+    document.addEventListener("pointerdown", function (event) {
+        var root = event.target.closest("[data-tooltip]");
+        root !== null && hide(parts(root));
+    });
+})();
@@ -2,8 +2,8 @@ use std::fs;
 use std::io::Read;
 
 use axum::{
-    body::Bytes,
-    extract::Multipart,
+    extract::{multipart::MultipartError, Multipart},
+    http::Uri,
     response::IntoResponse,
     routing::{get, put},
 };
@@ -64,6 +64,8 @@ struct ServeCmdArgs {
 async fn serve(opts: ServeCmdArgs) {
     let route = axum::Router::new()
         .route("/", get(root_page))
+        .route("/docs", get(root_page))
+        .route("/docs/*path", get(root_page))
         .route("/nice", get(nice))
         .route("/upload", put(upload))
         .nest_service(
@@ -91,28 +93,128 @@ async fn main() {
     }
 }
 
-pub async fn upload(mut multipart: Multipart) -> impl axum::response::IntoResponse {
-    tokio::time::sleep(std::time::Duration::from_secs(2)).await;
-    let mut data = Bytes::new();
-    let mut name = String::new();
-    while let Some(field) = multipart.next_field().await.unwrap() {
-        name = field.name().unwrap().to_string();
-        if name == "file" {
-            data = field.bytes().await.unwrap();
-            break;
+#[derive(Default)]
+struct UploadForm {
+    title: String,
+    file: Option<UploadedFile>,
+}
+
+impl UploadForm {
+    async fn from_multipart(multipart: Multipart) -> Result<Self, MultipartError> {
+        let mut data = FormData::from_multipart(multipart).await?;
+        Ok(Self {
+            title: data.text("title").unwrap_or_default().trim().to_string(),
+            file: data.take_file("file"),
+        })
+    }
+}
+
+impl Validate for UploadForm {
+    fn validate(&self) -> FormErrors {
+        let mut errors = FormErrors::new();
+        if self.title.is_empty() {
+            errors.add("title", "Title is required.");
+        }
+        if self.file.is_none() {
+            errors.add("file", "Please choose a file to upload.");
         }
+        errors
     }
+}
 
-    if name != "file" {
-        println!("not a file");
+fn upload_form(values: &UploadForm, errors: &FormErrors) -> Markup {
+    Form::new("upload-form")
+        .hx_put("/upload")
+        .multipart(true)
+        .build(html! {
+            @if !errors.is_empty() {
+                (Alert::destructive()
+                    .icon(html! {
+                        svg class="h-4 w-4" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round" viewBox="0 0 24 24" xmlns="http://www.w3.org/2000/svg" {
+                            circle cx="12" cy="12" r="10" {}
+                            line x1="12" x2="12" y1="8" y2="12" {}
+                            line x1="12" x2="12.01" y1="16" y2="16" {}
+                        }
+                    })
+                    .title("Error")
+                    .description("The file could not be uploaded.")
+                    .build())
+            }
+            (errors.field("title").label("Title").build(Input::new().name("title").value(&values.title)))
+            (errors
+                .field("file")
+                .label("File")
+                .description("Up to 512 MB.")
+                .build(FileUploader::new().class("border p-2").name("file")))
+            div class="flex gap-2 mt-2 items-center" {
+                (Button::new().class("w-fit flex gap-2 items-center justify-center").build(html! { "Submit" }))
+                progress class="text-red-600 bg-blue-400" id="progress" value="0" max="100" {}
+            }
+        })
+}
+
+pub async fn upload(multipart: Multipart) -> impl axum::response::IntoResponse {
+    tokio::time::sleep(std::time::Duration::from_secs(2)).await;
+    let values = match UploadForm::from_multipart(multipart).await {
+        Ok(values) => values,
+        Err(e) => return (e.status(), e.body_text()).into_response(),
+    };
+
+    let errors = values.validate();
+    if !errors.is_empty() {
+        let toasts = Toasts::new().push(
+            Toast::destructive()
+                .title("Upload failed")
+                .description("Please fix the errors in the form."),
+        );
+        return (toasts, upload_form(&values, &errors)).into_response();
     }
 
-    println!("File => {}", data.len());
+    let file = values.file.expect("validated file");
 
-    "nice".into_response()
+    let toast = Toast::new().title("File uploaded").description(format!(
+        "{} ({} bytes)",
+        file.file_name,
+        file.bytes.len()
+    ));
+    html! {
+        (upload_form(&UploadForm::default(), &FormErrors::new()))
+        (toast.oob())
+    }
+    .into_response()
 }
 
-async fn root_page() -> Markup {
+async fn root_page(uri: Uri) -> Markup {
+    let site = vec![
+        MenuEntry::link("Home", "/"),
+        MenuEntry::menu(
+            "Getting started",
+            vec![
+                MenuEntry::link("Introduction", "/docs")
+                    .description("Re-usable components built with maud, htmx and Tailwind CSS."),
+                MenuEntry::link("Installation", "/docs/installation")
+                    .description("How to install dependencies and structure your app."),
+            ],
+        ),
+        MenuEntry::menu(
+            "Components",
+            vec![
+                MenuEntry::link("Dialog", "/docs/components/dialog")
+                    .description("A window overlaid on the primary window."),
+                MenuEntry::link("Tooltip", "/docs/components/tooltip")
+                    .description("Information shown when an element is hovered or focused."),
+                MenuEntry::Separator,
+                MenuEntry::menu(
+                    "Forms",
+                    vec![
+                        MenuEntry::link("Input", "/docs/components/input"),
+                        MenuEntry::link("Select", "/docs/components/select"),
+                    ],
+                ),
+            ],
+        ),
+    ];
+
     html! {
         html lang="id" translate="no";
         head {
@@ -123,6 +225,16 @@ async fn root_page() -> Markup {
         }
         body class="bg-background flex flex-col h-screen items-center justify-center" {
             div class="flex flex-col gap-4" {
+                (NavigationMenu::new("main-nav", &site).current_path(uri.path()).build())
+                (Menubar::from_entries("menubar", &site).build())
+                (Breadcrumb::from_uri(&uri, |segment| match segment {
+                    "" => "Home".to_string(),
+                    "docs" => "Docs".to_string(),
+                    segment => segment.replace('-', " "),
+                })
+                .max_items(4)
+                .build())
+
                 span {
                     "This text should change color in dark mode"
                 }
@@ -134,39 +246,129 @@ async fn root_page() -> Markup {
                     {(Button::ghost().build(html! { "Ghost" }))}
                     {(Button::destructive().build(html! { "Destructive" }))}
                     {(Button::link().build(html! { "Link" }))}
+                    (Button::outline().size(ButtonSize::Icon).tooltip("Add to library").build(html! {
+                        svg class="h-4 w-4" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round" viewBox="0 0 24 24" xmlns="http://www.w3.org/2000/svg" aria-hidden="true" {
+                            path d="M5 12h14" {}
+                            path d="M12 5v14" {}
+                        }
+                    }))
+                }
+
+                div class="flex gap-2" {
+                    (Badge::new().build(html! { "Badge" }))
+                    (Badge::secondary().build(html! { "Secondary" }))
+                    (Badge::outline().href("/").build(html! { "Outline" }))
+                    (Badge::destructive().build(html! { "Destructive" }))
+                }
+
+                div class="flex gap-4 items-center" {
+                    (Avatar::new().src("https://github.com/shadcn.png").alt("@shadcn").initials("CN").build())
+                    (Avatar::new().src("/missing.png").alt("John Doe").initials("JD").build())
+                    (AvatarGroup::new()
+                        .max(3)
+                        .size(AvatarSize::Sm)
+                        .avatar(Avatar::new().initials("AB"))
+                        .avatar(Avatar::new().initials("CD"))
+                        .avatar(Avatar::new().initials("EF"))
+                        .avatar(Avatar::new().initials("GH"))
+                        .avatar(Avatar::new())
+                        .build())
+                }
+
+                div class="flex gap-2 items-center" {
+                    (DropdownMenu::new("account-menu")
+                        .trigger(Button::outline(), html! { "Open" })
+                        .content(
+                            DropdownMenuContent::new()
+                                .label("My Account")
+                                .separator()
+                                .item(DropdownMenuItem::new("Profile").href("/").shortcut("⇧⌘P"))
+                                .item(DropdownMenuItem::new("Nice").hx_get("/nice").hx_target("#nice").shortcut("⌘N"))
+                                .item(DropdownMenuItem::new("Billing").disabled(true))
+                                .separator()
+                                .checkbox_item(DropdownMenuCheckboxItem::new("Status bar").checked(true))
+                                .submenu(DropdownMenuSub::new(
+                                    "Position",
+                                    DropdownMenuContent::new().radio_group(
+                                        DropdownMenuRadioGroup::new("position")
+                                            .value("top")
+                                            .item(DropdownMenuRadioItem::new("top", "Top"))
+                                            .item(DropdownMenuRadioItem::new("bottom", "Bottom")),
+                                    ),
+                                )),
+                        )
+                        .build())
+                    (Popover::new("dimensions")
+                        .trigger(Button::outline(), html! { "Dimensions" })
+                        .build(html! {
+                            div class="grid gap-2" {
+                                h4 class="font-medium leading-none" { "Dimensions" }
+                                p class="text-sm text-muted-foreground" { "Set the dimensions for the layer." }
+                                (Input::new().placeholder("Width").build())
+                            }
+                        }))
+                    (Sheet::new("edit-profile")
+                        .trigger(Button::outline().ty("button").build(html! { "Edit profile" }))
+                        .title("Edit profile")
+                        .description("Make changes to your profile here. Click save when you're done.")
+                        .footer(Button::new().ty("submit").build(html! { "Save changes" }))
+                        .build(html! {
+                            (FormField::new("name").label("Name").build(Input::new().value("Pedro Duarte")))
+                        }))
+                    (Drawer::new("move-goal")
+                        .trigger(Button::outline().ty("button").build(html! { "Open drawer" }))
+                        .title("Move goal")
+                        .description("Set your daily activity goal.")
+                        .snap_points(&[0.5, 0.9])
+                        .footer(html! {
+                            (Button::new().ty("button").build(html! { "Submit" }))
+                        })
+                        .build(html! {
+                            p class="text-center text-4xl font-bold" { "350" }
+                        }))
+                    (CommandDialog::new(
+                        "command-palette",
+                        Command::new("command")
+                            .group(
+                                CommandGroup::new()
+                                    .heading("Suggestions")
+                                    .item(CommandItem::new("Calendar"))
+                                    .item(CommandItem::new("Nice").keywords("hello").hx_get("/nice").hx_target("#nice")),
+                            )
+                            .separator()
+                            .group(
+                                CommandGroup::new()
+                                    .heading("Settings")
+                                    .item(CommandItem::new("Profile").href("/").shortcut("⌘P"))
+                                    .item(CommandItem::new("Billing").disabled(true)),
+                            ),
+                    )
+                    .trigger(Button::outline().ty("button").build(html! { "Search ⌘K" }))
+                    .build())
+                    span id="nice" {}
                 }
 
                 div {
                     {(Input::new().class("w-fit").ty("text").placeholder("username").build())}
                 }
 
-                form
-                    hx-encoding="multipart/form-data"
-                    hx-swap="none"
-                    hx-put="/upload"
-                    _="on htmx:xhr:progress(loaded, total) set #progress.value to (loaded/total) * 100" {
-                        {(FileUploader::new().class("border p-2").name("file").build())}
-
-                        div class="flex gap-2 mt-2 items-center"{
-                            {(Button::new().class("w-fit flex gap-2 items-center justify-center")
-                              .build(html! {
-                                  "Submit"
-                                      // div role="status" id="upload-indicator" class="hidden htmx-indicator" {
-                                      //     svg aria-hidden="true" class="inline w-6 h-6 text-gray-200 animate-spin dark:text-gray-600 fill-gray-600 dark:fill-gray-300" viewBox="0 0 100 101" fill="none" xmlns="http://www.w3.org/2000/svg" {
-                                      //         path d="M100 50.5908C100 78.2051 77.6142 100.591 50 100.591C22.3858 100.591 0 78.2051 0 50.5908C0 22.9766 22.3858 0.59082 50 0.59082C77.6142 0.59082 100 22.9766 100 50.5908ZM9.08144 50.5908C9.08144 73.1895 27.4013 91.5094 50 91.5094C72.5987 91.5094 90.9186 73.1895 90.9186 50.5908C90.9186 27.9921 72.5987 9.67226 50 9.67226C27.4013 9.67226 9.08144 27.9921 9.08144 50.5908Z" fill="currentColor" {}
-                                      //         path d="M93.9676 39.0409C96.393 38.4038 97.8624 35.9116 97.0079 33.5539C95.2932 28.8227 92.871 24.3692 89.8167 20.348C85.8452 15.1192 80.8826 10.7238 75.2124 7.41289C69.5422 4.10194 63.2754 1.94025 56.7698 1.05124C51.7666 0.367541 46.6976 0.446843 41.7345 1.27873C39.2613 1.69328 37.813 4.19778 38.4501 6.62326C39.0873 9.04874 41.5694 10.4717 44.0505 10.1071C47.8511 9.54855 51.7191 9.52689 55.5402 10.0491C60.8642 10.7766 65.9928 12.5457 70.6331 15.2552C75.2735 17.9648 79.3347 21.5619 82.5849 25.841C84.9175 28.9121 86.7997 32.2913 88.1811 35.8758C89.083 38.2158 91.5421 39.6781 93.9676 39.0409Z" fill="currentFill" {}
-                                      //     }
-                                      //     span class="sr-only" { "Loading..." }
-                                      // }
-                              }))}
-                            progress class="text-red-600 bg-blue-400" id="progress" value="0" max="100" {}
-                        }
-                    }
+                div _="on htmx:xhr:progress(loaded, total) set #progress.value to (loaded/total) * 100" {
+                    (upload_form(&UploadForm::default(), &FormErrors::new()))
+                }
             }
         }
+        (Toaster::new().build())
         script {
             (PreEscaped(include_str!("../scripts/theme_toggle.js")))
         }
+        (ui_toaster_script())
+        (ui_dropdown_menu_script())
+        (ui_floating_script())
+        (ui_tooltip_script())
+        (ui_drawer_script())
+        (ui_command_script())
+        (ui_navigation_menu_script())
+        (ui_menubar_script())
     }
 }
 
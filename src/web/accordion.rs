@@ -0,0 +1,162 @@
+use maud::{html, Markup};
+
+use super::{
+    collapsible::{content_script, trigger_script, CONTENT_CLASSES},
+    with_class,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AccordionType {
+    /// Only one item can be open at a time.
+    Single,
+    /// Items open and close independently.
+    Multiple,
+}
+
+pub struct AccordionItem<'a> {
+    value: &'a str,
+    title: &'a str,
+    content: Markup,
+    disabled: bool,
+}
+
+impl<'a> AccordionItem<'a> {
+    pub fn new(value: &'a str, title: &'a str) -> Self {
+        Self {
+            value,
+            title,
+            content: html! {},
+            disabled: false,
+        }
+    }
+
+    pub fn content(mut self, content: Markup) -> Self {
+        self.content = content;
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+/// Vertically stacked set of collapsible sections, see [`Collapsible`](super::collapsible::Collapsible).
+pub struct Accordion<'a> {
+    id: &'a str,
+    ty: AccordionType,
+    collapsible: bool,
+    default_open: Vec<&'a str>,
+    items: Vec<AccordionItem<'a>>,
+    class: Option<&'a str>,
+}
+
+impl<'a> Accordion<'a> {
+    pub fn new(id: &'a str) -> Self {
+        Self {
+            id,
+            ty: AccordionType::Single,
+            collapsible: false,
+            default_open: Vec::new(),
+            items: Vec::new(),
+            class: None,
+        }
+    }
+
+    pub fn ty(mut self, ty: AccordionType) -> Self {
+        self.ty = ty;
+        self
+    }
+
+    /// In `Single` mode, allow closing the open item by clicking its trigger.
+    pub fn collapsible(mut self, collapsible: bool) -> Self {
+        self.collapsible = collapsible;
+        self
+    }
+
+    /// Value of an item that is open on first render. In `Single` mode only
+    /// the first one is used.
+    pub fn default_open(mut self, value: &'a str) -> Self {
+        self.default_open.push(value);
+        self
+    }
+
+    pub fn item(mut self, item: AccordionItem<'a>) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    pub fn class(mut self, class: &'a str) -> Self {
+        self.class = Some(class);
+        self
+    }
+
+    pub fn build(self) -> Markup {
+        accordion(self)
+    }
+}
+
+fn accordion(props: Accordion) -> Markup {
+    let trigger_classes = "flex flex-1 items-center justify-between py-4 font-medium transition-all hover:underline disabled:pointer-events-none disabled:opacity-50 [&[data-state=open]>svg]:rotate-180";
+
+    let open_values = match props.ty {
+        AccordionType::Single => &props.default_open[..props.default_open.len().min(1)],
+        AccordionType::Multiple => &props.default_open[..],
+    };
+
+    let close_others = "for other in <[data-accordion-trigger][aria-expanded='true']/> in (closest <[data-accordion]/>) \
+                          send collapsible:close to other \
+                        end";
+    let on_click = match (props.ty, props.collapsible) {
+        (AccordionType::Multiple, _) => "on click send collapsible:toggle to me".to_string(),
+        (AccordionType::Single, true) => format!(
+            "on click if @aria-expanded is 'false' {close_others} end send collapsible:toggle to me"
+        ),
+        (AccordionType::Single, false) => format!(
+            "on click if @aria-expanded is 'true' exit end {close_others} send collapsible:open to me"
+        ),
+    };
+
+    html! {
+        div id=(props.id) class=[props.class] data-accordion {
+            @for item in props.items {
+                @let open = open_values.contains(&item.value);
+                @let state = if open { "open" } else { "closed" };
+                @let trigger_id = format!("{}-trigger-{}", props.id, item.value);
+                @let content_id = format!("{}-content-{}", props.id, item.value);
+                div class="border-b border-border" data-state=(state) {
+                    h3 class="flex" {
+                        button
+                            type="button"
+                            class=(trigger_classes)
+                            id=(trigger_id)
+                            aria-expanded=(open)
+                            aria-controls=(content_id)
+                            data-state=(state)
+                            disabled[item.disabled]
+                            data-accordion-trigger
+                            _=(trigger_script(&content_id, &on_click))
+                        {
+                            (item.title)
+                            svg class="h-4 w-4 shrink-0 transition-transform duration-200" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round" viewBox="0 0 24 24" xmlns="http://www.w3.org/2000/svg" {
+                                path d="m6 9 6 6 6-6" {}
+                            }
+                        }
+                    }
+                    div
+                        id=(content_id)
+                        class=(with_class(CONTENT_CLASSES, Some("text-sm transition-all")))
+                        role="region"
+                        aria-labelledby=(trigger_id)
+                        data-state=(state)
+                        _=(content_script())
+                    {
+                        div class="pb-4 pt-0" {
+                            (item.content)
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
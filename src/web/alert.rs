@@ -0,0 +1,99 @@
+use maud::{html, Markup};
+
+use super::with_class;
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum AlertVariant {
+    #[default]
+    Default,
+    Destructive,
+}
+
+/// Inline callout, e.g. a warning or an error banner above a form.
+#[derive(Default)]
+pub struct Alert<'a> {
+    variant: AlertVariant,
+    title: Option<&'a str>,
+    description: Option<&'a str>,
+    icon: Option<Markup>,
+    id: Option<&'a str>,
+    class: Option<&'a str>,
+}
+
+impl<'a> Alert<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn destructive() -> Self {
+        Self::default().variant(AlertVariant::Destructive)
+    }
+
+    pub fn variant(mut self, variant: AlertVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    pub fn description(mut self, description: &'a str) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    /// Icon shown before the text, usually a `h-4 w-4` svg.
+    pub fn icon(mut self, icon: Markup) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    pub fn id(mut self, id: &'a str) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    pub fn class(mut self, class: &'a str) -> Self {
+        self.class = Some(class);
+        self
+    }
+
+    pub fn build(self) -> Markup {
+        alert(self)
+    }
+}
+
+fn alert(props: Alert) -> Markup {
+    // The icon is taken out of the flow and the text shifted next to it.
+    let base_classes = "relative w-full rounded-lg border p-4 [&>svg~*]:pl-7 [&>svg+div]:translate-y-[-3px] [&>svg]:absolute [&>svg]:left-4 [&>svg]:top-4";
+    let variant_classes = match props.variant {
+        AlertVariant::Default => "border-border bg-background text-foreground [&>svg]:text-foreground",
+        AlertVariant::Destructive => {
+            "border-destructive/50 text-destructive dark:border-destructive [&>svg]:text-destructive"
+        }
+    };
+
+    html! {
+        div
+            class=(with_class(&format!("{} {}", base_classes, variant_classes), props.class))
+            id=[props.id]
+            role="alert"
+        {
+            @if let Some(icon) = props.icon {
+                (icon)
+            }
+            @if let Some(title) = props.title {
+                h5 class="mb-1 font-medium leading-none tracking-tight" {
+                    (title)
+                }
+            }
+            @if let Some(description) = props.description {
+                div class="text-sm [&_p]:leading-relaxed" {
+                    (description)
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use maud::{html, Markup};
+
+use super::{
+    button::Button,
+    dialog::{close_script, footer, header, open_script},
+    with_class,
+};
+
+/// Modal confirmation step for destructive actions.
+///
+/// The `action` (typically a [`Button`] carrying `hx_post`/`hx_delete`) lives
+/// inside the dialog, so its request is only sent once the user confirms.
+/// Unlike [`Dialog`](super::dialog::Dialog), clicking the backdrop does not
+/// dismiss it; the user has to pick cancel or the action (or press Escape).
+pub struct AlertDialog<'a> {
+    id: &'a str,
+    trigger: Option<Markup>,
+    title: Option<&'a str>,
+    description: Option<&'a str>,
+    cancel_label: &'a str,
+    action: Option<Markup>,
+    class: Option<&'a str>,
+}
+
+impl<'a> AlertDialog<'a> {
+    pub fn new(id: &'a str) -> Self {
+        Self {
+            id,
+            trigger: None,
+            title: None,
+            description: None,
+            cancel_label: "Cancel",
+            action: None,
+            class: None,
+        }
+    }
+
+    pub fn trigger(mut self, trigger: Markup) -> Self {
+        self.trigger = Some(trigger);
+        self
+    }
+
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    pub fn description(mut self, description: &'a str) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    pub fn cancel_label(mut self, cancel_label: &'a str) -> Self {
+        self.cancel_label = cancel_label;
+        self
+    }
+
+    /// Confirming element; the dialog is closed once it is clicked.
+    pub fn action(mut self, action: Markup) -> Self {
+        self.action = Some(action);
+        self
+    }
+
+    pub fn class(mut self, class: &'a str) -> Self {
+        self.class = Some(class);
+        self
+    }
+
+    pub fn build(self) -> Markup {
+        alert_dialog(self)
+    }
+}
+
+fn alert_dialog(props: AlertDialog) -> Markup {
+    let base_classes = "w-full max-w-lg border border-border bg-popover p-0 text-popover-foreground shadow-lg sm:rounded-lg backdrop:bg-black/80";
+    let classes = with_class(base_classes, props.class);
+
+    let title_id = format!("{}-title", props.id);
+    let description_id = format!("{}-description", props.id);
+
+    html! {
+        @if let Some(trigger) = props.trigger {
+            span class="contents" _=(open_script(props.id)) {
+                (trigger)
+            }
+        }
+        dialog
+            id=(props.id)
+            class=(classes)
+            role="alertdialog"
+            aria-modal="true"
+            aria-labelledby=[props.title.map(|_| &title_id)]
+            aria-describedby=[props.description.map(|_| &description_id)]
+        {
+            div class="grid gap-4 p-6" {
+                (header(&title_id, props.title, &description_id, props.description))
+                (footer(html! {
+                    span class="contents" _=(close_script(props.id)) {
+                        (Button::outline().ty("button").build(html! { (props.cancel_label) }))
+                    }
+                    @if let Some(action) = props.action {
+                        span class="contents" _=(close_script(props.id)) {
+                            (action)
+                        }
+                    }
+                }))
+            }
+        }
+    }
+}
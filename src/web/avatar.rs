@@ -0,0 +1,204 @@
+use maud::{html, Markup};
+
+use super::with_class;
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum AvatarSize {
+    Sm,
+    #[default]
+    Default,
+    Lg,
+}
+
+impl AvatarSize {
+    fn classes(self) -> &'static str {
+        match self {
+            AvatarSize::Sm => "h-8 w-8 text-xs",
+            AvatarSize::Default => "h-10 w-10 text-sm",
+            AvatarSize::Lg => "h-12 w-12 text-base",
+        }
+    }
+}
+
+const AVATAR_CLASSES: &str =
+    "relative flex shrink-0 overflow-hidden rounded-full bg-muted text-muted-foreground";
+
+/// User picture falling back to initials (or an icon) when there is no image
+/// or it fails to load.
+#[derive(Default)]
+pub struct Avatar<'a> {
+    src: Option<&'a str>,
+    alt: &'a str,
+    initials: Option<&'a str>,
+    icon: Option<Markup>,
+    size: AvatarSize,
+    id: Option<&'a str>,
+    class: Option<&'a str>,
+}
+
+impl<'a> Avatar<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn src(mut self, src: &'a str) -> Self {
+        self.src = Some(src);
+        self
+    }
+
+    pub fn alt(mut self, alt: &'a str) -> Self {
+        self.alt = alt;
+        self
+    }
+
+    /// Text shown in place of the image, e.g. `"JD"`.
+    pub fn initials(mut self, initials: &'a str) -> Self {
+        self.initials = Some(initials);
+        self
+    }
+
+    /// Icon shown in place of the image when there are no initials.
+    pub fn icon(mut self, icon: Markup) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    pub fn size(mut self, size: AvatarSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn id(mut self, id: &'a str) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    pub fn class(mut self, class: &'a str) -> Self {
+        self.class = Some(class);
+        self
+    }
+
+    pub fn build(self) -> Markup {
+        avatar(self)
+    }
+}
+
+fn avatar(props: Avatar) -> Markup {
+    let classes = with_class(
+        &format!("{} {}", AVATAR_CLASSES, props.size.classes()),
+        props.class,
+    );
+
+    // The fallback is always rendered under the image, which removes itself
+    // when it fails to load (including before hyperscript was initialized)
+    // and hands its accessible name over to the fallback. Without `alt` there
+    // is nothing to announce, the avatar is decorative.
+    let named = !props.alt.is_empty();
+    let on_error = if named {
+        "remove @aria-hidden from my previousElementSibling then remove me"
+    } else {
+        "remove me"
+    };
+
+    html! {
+        span class=(classes) id=[props.id] {
+            span
+                class="flex h-full w-full items-center justify-center rounded-full font-medium"
+                role=[named.then_some("img")]
+                aria-label=[named.then_some(props.alt)]
+                aria-hidden=[(props.src.is_some() || !named).then_some("true")]
+            {
+                @if let Some(initials) = props.initials {
+                    (initials)
+                } @else if let Some(icon) = props.icon {
+                    (icon)
+                } @else {
+                    svg class="h-1/2 w-1/2" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round" viewBox="0 0 24 24" xmlns="http://www.w3.org/2000/svg" aria-hidden="true" {
+                        path d="M19 21v-2a4 4 0 0 0-4-4H9a4 4 0 0 0-4 4v2" {}
+                        circle cx="12" cy="7" r="4" {}
+                    }
+                }
+            }
+            @if let Some(src) = props.src {
+                img
+                    class="absolute inset-0 aspect-square h-full w-full object-cover"
+                    src=(src)
+                    alt=(props.alt)
+                    _=(format!("on error {} end init if my.complete and my.naturalWidth is 0 then trigger error end", on_error));
+            }
+        }
+    }
+}
+
+/// Overlapping avatars, the ones beyond `max` being summarized as "+N".
+pub struct AvatarGroup<'a> {
+    avatars: Vec<Avatar<'a>>,
+    max: Option<usize>,
+    size: AvatarSize,
+    class: Option<&'a str>,
+}
+
+impl<'a> Default for AvatarGroup<'a> {
+    fn default() -> Self {
+        Self {
+            avatars: Vec::new(),
+            max: None,
+            size: AvatarSize::Default,
+            class: None,
+        }
+    }
+}
+
+impl<'a> AvatarGroup<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn avatar(mut self, avatar: Avatar<'a>) -> Self {
+        self.avatars.push(avatar);
+        self
+    }
+
+    /// Number of avatars shown before the "+N" overflow.
+    pub fn max(mut self, max: usize) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Size of every avatar of the group.
+    pub fn size(mut self, size: AvatarSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn class(mut self, class: &'a str) -> Self {
+        self.class = Some(class);
+        self
+    }
+
+    pub fn build(self) -> Markup {
+        avatar_group(self)
+    }
+}
+
+fn avatar_group(props: AvatarGroup) -> Markup {
+    let shown = props.max.unwrap_or(props.avatars.len());
+    let overflow = props.avatars.len().saturating_sub(shown);
+
+    html! {
+        div class=(with_class("flex -space-x-2 [&>*]:ring-2 [&>*]:ring-background", props.class)) {
+            @for avatar in props.avatars.into_iter().take(shown) {
+                (avatar.size(props.size).build())
+            }
+            @if overflow > 0 {
+                span
+                    class=(format!("{} {} items-center justify-center font-medium", AVATAR_CLASSES, props.size.classes()))
+                    role="img"
+                    aria-label=(format!("{} more", overflow))
+                {
+                    "+" (overflow)
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use maud::{html, Markup};
+
+use super::with_class;
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum BadgeVariant {
+    #[default]
+    Default,
+    Secondary,
+    Outline,
+    Destructive,
+}
+
+/// Small status label, rendered as a link with `href` or as a button with
+/// `hx_get`.
+#[derive(Default)]
+pub struct Badge<'a> {
+    variant: BadgeVariant,
+    href: Option<&'a str>,
+    hx_get: Option<&'a str>,
+    hx_target: Option<&'a str>,
+    hx_swap: Option<&'a str>,
+    id: Option<&'a str>,
+    class: Option<&'a str>,
+}
+
+impl<'a> Badge<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn secondary() -> Self {
+        Self::default().variant(BadgeVariant::Secondary)
+    }
+
+    pub fn outline() -> Self {
+        Self::default().variant(BadgeVariant::Outline)
+    }
+
+    pub fn destructive() -> Self {
+        Self::default().variant(BadgeVariant::Destructive)
+    }
+
+    pub fn variant(mut self, variant: BadgeVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    pub fn href(mut self, href: &'a str) -> Self {
+        self.href = Some(href);
+        self
+    }
+
+    pub fn hx_get(mut self, hx_get: &'a str) -> Self {
+        self.hx_get = Some(hx_get);
+        self
+    }
+
+    pub fn hx_target(mut self, hx_target: &'a str) -> Self {
+        self.hx_target = Some(hx_target);
+        self
+    }
+
+    pub fn hx_swap(mut self, hx_swap: &'a str) -> Self {
+        self.hx_swap = Some(hx_swap);
+        self
+    }
+
+    pub fn id(mut self, id: &'a str) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    pub fn class(mut self, class: &'a str) -> Self {
+        self.class = Some(class);
+        self
+    }
+
+    pub fn build(self, content: Markup) -> Markup {
+        badge(self, content)
+    }
+}
+
+fn badge(props: Badge, content: Markup) -> Markup {
+    let base_classes = "inline-flex items-center rounded-full border px-2.5 py-0.5 text-xs font-semibold transition-colors focus:outline-none focus:ring-2 focus:ring-ring focus:ring-offset-2";
+    let variant_classes = match props.variant {
+        BadgeVariant::Default => {
+            "border-transparent bg-primary text-primary-foreground hover:bg-primary/80"
+        }
+        BadgeVariant::Secondary => {
+            "border-transparent bg-secondary text-secondary-foreground hover:bg-secondary/80"
+        }
+        BadgeVariant::Outline => "border-border text-foreground",
+        BadgeVariant::Destructive => {
+            "border-transparent bg-destructive text-destructive-foreground hover:bg-destructive/80"
+        }
+    };
+    let classes = with_class(
+        &format!("{} {}", base_classes, variant_classes),
+        props.class,
+    );
+
+    html! {
+        @if let Some(href) = props.href {
+            a
+                class=(classes)
+                id=[props.id]
+                href=(href)
+                hx-get=[props.hx_get]
+                hx-target=[props.hx_target]
+                hx-swap=[props.hx_swap]
+            {
+                (content)
+            }
+        } @else if props.hx_get.is_some() {
+            button
+                class=(classes)
+                id=[props.id]
+                type="button"
+                hx-get=[props.hx_get]
+                hx-target=[props.hx_target]
+                hx-swap=[props.hx_swap]
+            {
+                (content)
+            }
+        } @else {
+            div class=(classes) id=[props.id] {
+                (content)
+            }
+        }
+    }
+}
@@ -0,0 +1,243 @@
+use axum::http::Uri;
+use maud::{html, Markup};
+use percent_encoding::percent_decode_str;
+
+use super::with_class;
+
+/// Step of a [`Breadcrumb`] trail.
+pub struct BreadcrumbItem {
+    label: String,
+    href: Option<String>,
+}
+
+impl BreadcrumbItem {
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            href: None,
+        }
+    }
+
+    /// Ignored for the last item, which is the current page.
+    pub fn href(mut self, href: impl Into<String>) -> Self {
+        self.href = Some(href.into());
+        self
+    }
+}
+
+/// Trail of links to the parents of the current page, the last item.
+///
+/// With `max_items`, the items between the first one and the last ones are
+/// replaced by an ellipsis.
+#[derive(Default)]
+pub struct Breadcrumb<'a> {
+    items: Vec<BreadcrumbItem>,
+    max_items: Option<usize>,
+    separator: Option<Markup>,
+    class: Option<&'a str>,
+}
+
+impl<'a> Breadcrumb<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Trail of the request path, one item per segment after the root.
+    ///
+    /// `label` gives the label of a percent-decoded segment, and is called
+    /// with `""` for the root.
+    ///
+    /// ```ignore
+    /// // "/docs/getting-started" -> Home > Docs > getting started
+    /// Breadcrumb::from_uri(&uri, |segment| match segment {
+    ///     "" => "Home".to_string(),
+    ///     "docs" => "Docs".to_string(),
+    ///     segment => segment.replace('-', " "),
+    /// })
+    /// ```
+    pub fn from_uri(uri: &Uri, label: impl Fn(&str) -> String) -> Self {
+        let mut href = String::new();
+        uri.path()
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+            .fold(
+                Self::new().item(BreadcrumbItem::new(label("")).href("/")),
+                |breadcrumb, segment| {
+                    href.push('/');
+                    href.push_str(segment);
+                    let decoded = percent_decode_str(segment).decode_utf8_lossy();
+                    breadcrumb.item(BreadcrumbItem::new(label(&decoded)).href(href.as_str()))
+                },
+            )
+    }
+
+    pub fn item(mut self, item: BreadcrumbItem) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    /// Maximum number of items shown, the ellipsis included (at least 3).
+    pub fn max_items(mut self, max_items: usize) -> Self {
+        self.max_items = Some(max_items);
+        self
+    }
+
+    /// Separator shown between items, a chevron by default.
+    pub fn separator(mut self, separator: Markup) -> Self {
+        self.separator = Some(separator);
+        self
+    }
+
+    pub fn class(mut self, class: &'a str) -> Self {
+        self.class = Some(class);
+        self
+    }
+
+    pub fn build(self) -> Markup {
+        breadcrumb(self)
+    }
+}
+
+/// Items to display, `None` standing for an ellipsis.
+fn visible_items(
+    items: &[BreadcrumbItem],
+    max_items: Option<usize>,
+) -> Vec<Option<&BreadcrumbItem>> {
+    match max_items {
+        // The first item, the ellipsis and at least the current page.
+        Some(max_items) if items.len() > max_items.max(3) => {
+            let max_items = max_items.max(3);
+            std::iter::once(Some(&items[0]))
+                .chain(std::iter::once(None))
+                .chain(items[items.len() - (max_items - 2)..].iter().map(Some))
+                .collect()
+        }
+        _ => items.iter().map(Some).collect(),
+    }
+}
+
+fn breadcrumb(props: Breadcrumb) -> Markup {
+    let separator = props.separator.unwrap_or_else(|| {
+        html! {
+            svg class="h-3.5 w-3.5" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round" viewBox="0 0 24 24" xmlns="http://www.w3.org/2000/svg" {
+                path d="m9 18 6-6-6-6" {}
+            }
+        }
+    });
+    let base_classes =
+        "flex flex-wrap items-center gap-1.5 break-words text-sm text-muted-foreground sm:gap-2.5";
+    let items = visible_items(&props.items, props.max_items);
+    let last = items.len().saturating_sub(1);
+
+    html! {
+        nav aria-label="breadcrumb" {
+            ol class=(with_class(base_classes, props.class)) {
+                @for (index, item) in items.into_iter().enumerate() {
+                    @if index > 0 {
+                        li class="flex items-center" role="presentation" aria-hidden="true" {
+                            (separator)
+                        }
+                    }
+                    li class="inline-flex items-center gap-1.5" {
+                        @match item {
+                            Some(item) if index == last => {
+                                span class="font-normal text-foreground" role="link" aria-disabled="true" aria-current="page" {
+                                    (item.label)
+                                }
+                            },
+                            Some(BreadcrumbItem { label, href: Some(href) }) => {
+                                a class="transition-colors hover:text-foreground" href=(href) {
+                                    (label)
+                                }
+                            },
+                            Some(BreadcrumbItem { label, href: None }) => {
+                                span { (label) }
+                            },
+                            None => {
+                                span class="flex h-9 w-9 items-center justify-center" aria-hidden="true" {
+                                    "…"
+                                }
+                                span class="sr-only" { "More pages" }
+                            },
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{visible_items, Breadcrumb, BreadcrumbItem};
+
+    fn labels(count: usize, max_items: Option<usize>) -> Vec<Option<String>> {
+        let items: Vec<_> = (1..=count)
+            .map(|n| BreadcrumbItem::new(n.to_string()))
+            .collect();
+        visible_items(&items, max_items)
+            .into_iter()
+            .map(|item| item.map(|item| item.label.clone()))
+            .collect()
+    }
+
+    fn expected(labels: &[Option<&str>]) -> Vec<Option<String>> {
+        labels.iter().map(|label| label.map(String::from)).collect()
+    }
+
+    #[test]
+    fn all_items_without_max() {
+        assert_eq!(
+            labels(5, None),
+            expected(&[Some("1"), Some("2"), Some("3"), Some("4"), Some("5")])
+        );
+        assert_eq!(
+            labels(3, Some(3)),
+            expected(&[Some("1"), Some("2"), Some("3")])
+        );
+        assert_eq!(labels(0, Some(3)), expected(&[]));
+    }
+
+    #[test]
+    fn collapses_middle_items() {
+        assert_eq!(
+            labels(5, Some(4)),
+            expected(&[Some("1"), None, Some("4"), Some("5")])
+        );
+        assert_eq!(labels(5, Some(3)), expected(&[Some("1"), None, Some("5")]));
+    }
+
+    #[test]
+    fn from_uri_decodes_labels_only() {
+        let uri = "/docs/my%20page/".parse().unwrap();
+        let breadcrumb = Breadcrumb::from_uri(&uri, |segment| segment.to_uppercase());
+        let items: Vec<_> = breadcrumb
+            .items
+            .iter()
+            .map(|item| (item.label.as_str(), item.href.as_deref()))
+            .collect();
+
+        assert_eq!(
+            items,
+            vec![
+                ("", Some("/")),
+                ("DOCS", Some("/docs")),
+                ("MY PAGE", Some("/docs/my%20page"))
+            ]
+        );
+    }
+
+    #[test]
+    fn max_below_three_keeps_first_and_last() {
+        for max_items in 0..3 {
+            assert_eq!(
+                labels(5, Some(max_items)),
+                expected(&[Some("1"), None, Some("5")])
+            );
+            assert_eq!(
+                labels(3, Some(max_items)),
+                expected(&[Some("1"), Some("2"), Some("3")])
+            );
+        }
+    }
+}
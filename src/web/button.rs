@@ -1,5 +1,7 @@
 use maud::{html, Markup};
 
+use super::tooltip::Tooltip;
+
 pub enum ButtonVariant {
     Default,
     Secondary,
@@ -22,12 +24,19 @@ pub struct Button<'a> {
     ty: Option<&'a str>,
     hx_get: Option<&'a str>,
     hx_post: Option<&'a str>,
+    hx_put: Option<&'a str>,
+    hx_delete: Option<&'a str>,
+    hx_target: Option<&'a str>,
     hx_swap: Option<&'a str>,
     disabled: bool,
     class: Option<&'a str>,
     aria_label: Option<&'a str>,
     title: Option<&'a str>,
     id: Option<&'a str>,
+    aria_haspopup: Option<&'a str>,
+    aria_controls: Option<&'a str>,
+    popover_target: Option<&'a str>,
+    tooltip: Option<&'a str>,
 }
 
 impl<'a> Default for Button<'a> {
@@ -38,12 +47,19 @@ impl<'a> Default for Button<'a> {
             ty: None,
             hx_get: None,
             hx_post: None,
+            hx_put: None,
+            hx_delete: None,
+            hx_target: None,
             hx_swap: None,
             disabled: false,
             class: None,
             aria_label: None,
             title: None,
             id: None,
+            aria_haspopup: None,
+            aria_controls: None,
+            popover_target: None,
+            tooltip: None,
         }
     }
 }
@@ -98,8 +114,23 @@ impl<'a> Button<'a> {
         self
     }
 
+    pub fn hx_put(mut self, hx_put: &'a str) -> Self {
+        self.hx_put = Some(hx_put);
+        self
+    }
+
+    pub fn hx_delete(mut self, hx_delete: &'a str) -> Self {
+        self.hx_delete = Some(hx_delete);
+        self
+    }
+
+    pub fn hx_target(mut self, hx_target: &'a str) -> Self {
+        self.hx_target = Some(hx_target);
+        self
+    }
+
     pub fn hx_swap(mut self, hx_swap: &'a str) -> Self {
-        self.hx_post = Some(hx_swap);
+        self.hx_swap = Some(hx_swap);
         self
     }
 
@@ -128,15 +159,38 @@ impl<'a> Button<'a> {
         self
     }
 
+    /// Show `tooltip` on hover and focus, see [`Tooltip`]. It is also the
+    /// `aria_label` of [`ButtonSize::Icon`] buttons that don't have one.
+    pub fn tooltip(mut self, tooltip: &'a str) -> Self {
+        self.tooltip = Some(tooltip);
+        self
+    }
+
+    /// Mark the button as the trigger of the popup `aria_controls` (with
+    /// `aria-expanded`), for components using a [`Button`] as trigger.
+    pub(crate) fn popup(mut self, aria_haspopup: &'a str, aria_controls: &'a str) -> Self {
+        self.aria_haspopup = Some(aria_haspopup);
+        self.aria_controls = Some(aria_controls);
+        self
+    }
+
+    /// Toggle the element `popover_target` with the popover API.
+    pub(crate) fn popover_target(mut self, popover_target: &'a str) -> Self {
+        self.popover_target = Some(popover_target);
+        self
+    }
+
     pub fn build(self, content: Markup) -> Markup {
         button(self, content)
     }
 }
 
-fn button(props: Button, content: Markup) -> Markup {
+/// Classes of a button with `variant` and `size`, for elements that should
+/// look like a [`Button`] without being one (e.g. links).
+pub(crate) fn button_classes(variant: &ButtonVariant, size: &ButtonSize) -> String {
     let base_classes = "inline-flex items-center justify-center rounded-md text-sm font-medium ring-offset-background transition-colors focus-visible:outline-none focus-visible:ring-2 focus-visible:ring-ring focus-visible:ring-offset-2 disabled:pointer-events-none disabled:opacity-50";
 
-    let variant_classes = match props.variant {
+    let variant_classes = match variant {
         ButtonVariant::Default => "bg-primary text-primary-foreground hover:bg-primary/90 dark:bg-primary dark:text-primary-foreground dark:hover:bg-primary/90",
         ButtonVariant::Secondary => "bg-secondary text-secondary-foreground hover:bg-secondary/80 dark:bg-secondary dark:text-secondary-foreground dark:hover:bg-secondary/80",
         ButtonVariant::Outline => "border border-input bg-background hover:bg-accent hover:text-accent-foreground dark:border-input dark:bg-background dark:text-foreground dark:hover:bg-accent dark:hover:text-accent-foreground",
@@ -145,31 +199,51 @@ fn button(props: Button, content: Markup) -> Markup {
         ButtonVariant::Link => "text-primary underline-offset-4 hover:underline dark:text-primary",
     };
 
-    let size_classes = match props.size {
+    let size_classes = match size {
         ButtonSize::Default => "h-10 px-4 py-2",
         ButtonSize::Sm => "h-9 rounded-md px-3",
         ButtonSize::Lg => "h-11 rounded-md px-8",
         ButtonSize::Icon => "h-10 w-10",
     };
 
-    let mut classes = format!("{} {} {}", base_classes, variant_classes, size_classes);
+    format!("{} {} {}", base_classes, variant_classes, size_classes)
+}
+
+fn button(props: Button, content: Markup) -> Markup {
+    let mut classes = button_classes(&props.variant, &props.size);
     if let Some(additional_class) = props.class {
         classes.push_str(&format!(" {}", additional_class));
     }
+    let aria_label = match (props.aria_label, &props.size) {
+        (None, ButtonSize::Icon) => props.tooltip,
+        (aria_label, _) => aria_label,
+    };
 
-    html! {
+    let button = html! {
         button
             class=(classes)
             hx-get=[props.hx_get]
             hx-post=[props.hx_post]
+            hx-put=[props.hx_put]
+            hx-delete=[props.hx_delete]
+            hx-target=[props.hx_target]
             hx-swap=[props.hx_swap]
             disabled[props.disabled]
-            aria-label=[props.aria_label]
+            aria-label=[aria_label]
             title=[props.title]
             id=[props.id]
             type=[props.ty]
+            aria-haspopup=[props.aria_haspopup]
+            aria-expanded=[props.aria_haspopup.map(|_| "false")]
+            aria-controls=[props.aria_controls]
+            popovertarget=[props.popover_target]
         {
             (content)
         }
+    };
+
+    match props.tooltip {
+        Some(tooltip) => Tooltip::new(tooltip).build(button),
+        None => button,
     }
 }
@@ -0,0 +1,201 @@
+use maud::{html, Markup};
+
+use super::with_class;
+
+#[derive(Default)]
+pub struct Card<'a> {
+    id: Option<&'a str>,
+    class: Option<&'a str>,
+}
+
+impl<'a> Card<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn id(mut self, id: &'a str) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    pub fn class(mut self, class: &'a str) -> Self {
+        self.class = Some(class);
+        self
+    }
+
+    pub fn build(self, content: Markup) -> Markup {
+        let classes = with_class(
+            "rounded-lg border border-border bg-card text-card-foreground shadow-sm",
+            self.class,
+        );
+
+        html! {
+            div class=(classes) id=[self.id] {
+                (content)
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct CardHeader<'a> {
+    id: Option<&'a str>,
+    class: Option<&'a str>,
+}
+
+impl<'a> CardHeader<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn id(mut self, id: &'a str) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    pub fn class(mut self, class: &'a str) -> Self {
+        self.class = Some(class);
+        self
+    }
+
+    pub fn build(self, content: Markup) -> Markup {
+        let classes = with_class("flex flex-col space-y-1.5 p-6", self.class);
+
+        html! {
+            div class=(classes) id=[self.id] {
+                (content)
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct CardTitle<'a> {
+    id: Option<&'a str>,
+    class: Option<&'a str>,
+}
+
+impl<'a> CardTitle<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn id(mut self, id: &'a str) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    pub fn class(mut self, class: &'a str) -> Self {
+        self.class = Some(class);
+        self
+    }
+
+    pub fn build(self, content: Markup) -> Markup {
+        let classes = with_class(
+            "text-2xl font-semibold leading-none tracking-tight",
+            self.class,
+        );
+
+        html! {
+            h3 class=(classes) id=[self.id] {
+                (content)
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct CardDescription<'a> {
+    id: Option<&'a str>,
+    class: Option<&'a str>,
+}
+
+impl<'a> CardDescription<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn id(mut self, id: &'a str) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    pub fn class(mut self, class: &'a str) -> Self {
+        self.class = Some(class);
+        self
+    }
+
+    pub fn build(self, content: Markup) -> Markup {
+        let classes = with_class("text-sm text-muted-foreground", self.class);
+
+        html! {
+            p class=(classes) id=[self.id] {
+                (content)
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct CardContent<'a> {
+    id: Option<&'a str>,
+    class: Option<&'a str>,
+}
+
+impl<'a> CardContent<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn id(mut self, id: &'a str) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    pub fn class(mut self, class: &'a str) -> Self {
+        self.class = Some(class);
+        self
+    }
+
+    pub fn build(self, content: Markup) -> Markup {
+        let classes = with_class("p-6 pt-0", self.class);
+
+        html! {
+            div class=(classes) id=[self.id] {
+                (content)
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct CardFooter<'a> {
+    id: Option<&'a str>,
+    class: Option<&'a str>,
+}
+
+impl<'a> CardFooter<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn id(mut self, id: &'a str) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    pub fn class(mut self, class: &'a str) -> Self {
+        self.class = Some(class);
+        self
+    }
+
+    pub fn build(self, content: Markup) -> Markup {
+        let classes = with_class("flex items-center p-6 pt-0", self.class);
+
+        html! {
+            div class=(classes) id=[self.id] {
+                (content)
+            }
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use maud::{html, Markup};
+
+use super::with_class;
+
+#[derive(Default)]
+pub struct Checkbox<'a> {
+    id: Option<&'a str>,
+    name: Option<&'a str>,
+    value: Option<&'a str>,
+    class: Option<&'a str>,
+    checked: bool,
+    indeterminate: bool,
+    disabled: bool,
+    required: bool,
+    aria_describedby: Option<&'a str>,
+    invalid: bool,
+}
+
+impl<'a> Checkbox<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn id(mut self, id: &'a str) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    pub fn name(mut self, name: &'a str) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    pub fn value(mut self, value: &'a str) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    pub fn class(mut self, class: &'a str) -> Self {
+        self.class = Some(class);
+        self
+    }
+
+    pub fn checked(mut self, checked: bool) -> Self {
+        self.checked = checked;
+        self
+    }
+
+    /// Render the "mixed" state, e.g. for a "select all" checkbox. There is
+    /// no HTML attribute for it, so it is applied by hyperscript on load.
+    pub fn indeterminate(mut self, indeterminate: bool) -> Self {
+        self.indeterminate = indeterminate;
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    pub fn required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+
+    pub fn aria_describedby(mut self, aria_describedby: &'a str) -> Self {
+        self.aria_describedby = Some(aria_describedby);
+        self
+    }
+
+    /// Render the control in its error state (`aria-invalid` and destructive colors).
+    pub fn invalid(mut self, invalid: bool) -> Self {
+        self.invalid = invalid;
+        self
+    }
+
+    pub fn build(self) -> Markup {
+        checkbox(self)
+    }
+}
+
+fn checkbox(props: Checkbox) -> Markup {
+    let base_classes = "peer h-4 w-4 shrink-0 cursor-pointer appearance-none rounded-sm border border-primary ring-offset-background checked:bg-primary indeterminate:bg-primary focus-visible:outline-none focus-visible:ring-2 focus-visible:ring-ring focus-visible:ring-offset-2 disabled:cursor-not-allowed disabled:opacity-50 aria-[invalid=true]:border-destructive";
+    let classes = with_class(base_classes, props.class);
+
+    html! {
+        span class="relative inline-flex h-4 w-4 shrink-0" {
+            input
+                class=(classes)
+                type="checkbox"
+                id=[props.id]
+                name=[props.name]
+                value=[props.value]
+                checked[props.checked]
+                disabled[props.disabled]
+                aria-describedby=[props.aria_describedby]
+                aria-invalid=[props.invalid.then_some("true")]
+                required[props.required]
+                _=[props.indeterminate.then_some("init set my.indeterminate to true")] {}
+            svg class="pointer-events-none absolute inset-0 hidden h-4 w-4 text-primary-foreground peer-checked:block peer-indeterminate:hidden" fill="none" stroke="currentColor" stroke-width="3" stroke-linecap="round" stroke-linejoin="round" viewBox="0 0 24 24" xmlns="http://www.w3.org/2000/svg" {
+                path d="M20 6 9 17l-5-5" {}
+            }
+            svg class="pointer-events-none absolute inset-0 hidden h-4 w-4 text-primary-foreground peer-indeterminate:block" fill="none" stroke="currentColor" stroke-width="3" stroke-linecap="round" stroke-linejoin="round" viewBox="0 0 24 24" xmlns="http://www.w3.org/2000/svg" {
+                path d="M5 12h14" {}
+            }
+        }
+    }
+}
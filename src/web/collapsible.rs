@@ -0,0 +1,118 @@
+use maud::{html, Markup};
+
+/// Section that is shown or hidden by a trigger button.
+///
+/// Closed content is only hidden once hyperscript initializes, so it stays
+/// readable without JavaScript. Opening and closing use the
+/// `accordion-down`/`accordion-up` animations.
+pub struct Collapsible<'a> {
+    id: &'a str,
+    open: bool,
+    trigger: Option<Markup>,
+    trigger_class: Option<&'a str>,
+    class: Option<&'a str>,
+}
+
+impl<'a> Collapsible<'a> {
+    pub fn new(id: &'a str) -> Self {
+        Self {
+            id,
+            open: false,
+            trigger: None,
+            trigger_class: None,
+            class: None,
+        }
+    }
+
+    pub fn open(mut self, open: bool) -> Self {
+        self.open = open;
+        self
+    }
+
+    /// Content of the trigger button.
+    pub fn trigger(mut self, trigger: Markup) -> Self {
+        self.trigger = Some(trigger);
+        self
+    }
+
+    pub fn trigger_class(mut self, trigger_class: &'a str) -> Self {
+        self.trigger_class = Some(trigger_class);
+        self
+    }
+
+    pub fn class(mut self, class: &'a str) -> Self {
+        self.class = Some(class);
+        self
+    }
+
+    pub fn build(self, content: Markup) -> Markup {
+        collapsible(self, content)
+    }
+}
+
+fn collapsible(props: Collapsible, content: Markup) -> Markup {
+    let state = if props.open { "open" } else { "closed" };
+    let content_id = format!("{}-content", props.id);
+
+    html! {
+        div id=(props.id) class=[props.class] data-state=(state) {
+            @if let Some(trigger) = props.trigger {
+                button
+                    type="button"
+                    class=[props.trigger_class]
+                    aria-expanded=(props.open)
+                    aria-controls=(content_id)
+                    data-state=(state)
+                    _=(trigger_script(&content_id, "on click send collapsible:toggle to me"))
+                {
+                    (trigger)
+                }
+            }
+            div
+                id=(content_id)
+                class=(CONTENT_CLASSES)
+                data-state=(state)
+                _=(content_script())
+            {
+                (content)
+            }
+        }
+    }
+}
+
+pub(crate) const CONTENT_CLASSES: &str =
+    "overflow-hidden data-[state=closed]:animate-accordion-up data-[state=open]:animate-accordion-down";
+
+/// Hyperscript for a trigger controlling `content_id`, `on_click` decides
+/// which of the `collapsible:{toggle,open,close}` events to send.
+pub(crate) fn trigger_script(content_id: &str, on_click: &str) -> String {
+    format!(
+        "{on_click} end \
+         on collapsible:toggle \
+           if @aria-expanded is 'true' send collapsible:close to me else send collapsible:open to me end \
+         end \
+         on collapsible:open \
+           set @aria-expanded to 'true' then set @data-state to 'open' \
+           then send collapsible:open to #{content_id} \
+         end \
+         on collapsible:close \
+           set @aria-expanded to 'false' then set @data-state to 'closed' \
+           then send collapsible:close to #{content_id}"
+    )
+}
+
+/// Hyperscript animating the content element, it exposes its height through
+/// `--radix-accordion-content-height` as expected by the accordion keyframes.
+pub(crate) fn content_script() -> &'static str {
+    "init if @data-state is 'closed' add @hidden end end \
+     on collapsible:open \
+       remove @hidden \
+       call my.style.setProperty('--radix-accordion-content-height', my.scrollHeight + 'px') \
+       set @data-state to 'open' \
+     end \
+     on collapsible:close \
+       call my.style.setProperty('--radix-accordion-content-height', my.scrollHeight + 'px') \
+       set @data-state to 'closed' \
+       wait for animationend or 200ms \
+       if @data-state is 'closed' add @hidden end"
+}
@@ -0,0 +1,219 @@
+use maud::{html, Markup, PreEscaped};
+
+use super::{
+    floating::{Align, Placement, Side},
+    input::{InputSize, INPUT_CLASSES},
+    with_class,
+};
+
+/// Searchable picker whose options are fetched from `hx_get`.
+///
+/// Typing into the input sends `GET {hx_get}?{search_param}={text}` and the
+/// response, usually a list of [`ComboboxOption`], is swapped into the listbox.
+/// The chosen value is submitted through a hidden input named `name`, the
+/// search text is not submitted with the enclosing form.
+/// Requires [`ui_combobox_script`] and
+/// [`ui_floating_script`](super::floating::ui_floating_script) on the page.
+pub struct Combobox<'a> {
+    id: &'a str,
+    hx_get: &'a str,
+    search_param: &'a str,
+    size: InputSize,
+    name: Option<&'a str>,
+    value: Option<&'a str>,
+    label: Option<&'a str>,
+    placeholder: Option<&'a str>,
+    class: Option<&'a str>,
+    disabled: bool,
+    required: bool,
+    aria_describedby: Option<&'a str>,
+    invalid: bool,
+}
+
+impl<'a> Combobox<'a> {
+    pub fn new(id: &'a str, hx_get: &'a str) -> Self {
+        Self {
+            id,
+            hx_get,
+            search_param: "q",
+            size: InputSize::Default,
+            name: None,
+            value: None,
+            label: None,
+            placeholder: None,
+            class: None,
+            disabled: false,
+            required: false,
+            aria_describedby: None,
+            invalid: false,
+        }
+    }
+
+    /// Replaces the `id` given to `new`, e.g. by [`FormField`](super::form_field::FormField).
+    pub fn id(mut self, id: &'a str) -> Self {
+        self.id = id;
+        self
+    }
+
+    /// Query parameter carrying the search text, defaults to `q`.
+    pub fn search_param(mut self, search_param: &'a str) -> Self {
+        self.search_param = search_param;
+        self
+    }
+
+    pub fn size(mut self, size: InputSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn name(mut self, name: &'a str) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    /// Initially chosen `value` and the `label` shown in the input for it.
+    pub fn value(mut self, value: &'a str, label: &'a str) -> Self {
+        self.value = Some(value);
+        self.label = Some(label);
+        self
+    }
+
+    pub fn placeholder(mut self, placeholder: &'a str) -> Self {
+        self.placeholder = Some(placeholder);
+        self
+    }
+
+    pub fn class(mut self, class: &'a str) -> Self {
+        self.class = Some(class);
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    pub fn required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+
+    pub fn aria_describedby(mut self, aria_describedby: &'a str) -> Self {
+        self.aria_describedby = Some(aria_describedby);
+        self
+    }
+
+    /// Render the control in its error state (`aria-invalid` and destructive colors).
+    pub fn invalid(mut self, invalid: bool) -> Self {
+        self.invalid = invalid;
+        self
+    }
+
+    pub fn build(self) -> Markup {
+        combobox(self)
+    }
+}
+
+fn combobox(props: Combobox) -> Markup {
+    let listbox_classes = "z-50 max-h-72 w-[var(--floating-anchor-width)] overflow-auto rounded-md border border-border bg-popover p-1 text-popover-foreground shadow-md";
+    let placement = Placement::new(Side::Bottom, Align::Start, 4);
+    let classes = with_class(
+        &format!("{} {}", INPUT_CLASSES, props.size.classes()),
+        props.class,
+    );
+    let listbox_id = format!("{}-listbox", props.id);
+
+    html! {
+        div class="relative w-full" data-combobox {
+            // Hidden inputs are skipped by constraint validation, a visually
+            // hidden text input is used instead when a value is required.
+            input
+                class="sr-only"
+                type=(if props.required { "text" } else { "hidden" })
+                name=[props.name]
+                value=(props.value.unwrap_or(""))
+                tabindex="-1"
+                aria-hidden="true"
+                disabled[props.disabled]
+                required[props.required]
+                data-combobox-value {}
+            input
+                class=(classes)
+                type="text"
+                role="combobox"
+                id=(props.id)
+                value=[props.label]
+                placeholder=[props.placeholder]
+                autocomplete="off"
+                spellcheck="false"
+                aria-autocomplete="list"
+                aria-expanded="false"
+                aria-controls=(listbox_id)
+                aria-required=[props.required.then_some("true")]
+                aria-describedby=[props.aria_describedby]
+                aria-invalid=[props.invalid.then_some("true")]
+                hx-get=(props.hx_get)
+                hx-trigger="input changed delay:300ms"
+                hx-target=(format!("#{}", listbox_id))
+                disabled[props.disabled]
+                data-combobox-search-param=(props.search_param) {}
+            div
+                class=(listbox_classes)
+                role="listbox"
+                id=(listbox_id)
+                hidden
+                data-floating
+                data-floating-side=(placement.side.as_str())
+                data-floating-align=(placement.align.as_str())
+                data-floating-offset=(placement.side_offset) {}
+        }
+    }
+}
+
+/// Option rendered by the search endpoint of a [`Combobox`].
+pub struct ComboboxOption<'a> {
+    value: &'a str,
+    label: &'a str,
+    disabled: bool,
+}
+
+impl<'a> ComboboxOption<'a> {
+    pub fn new(value: &'a str, label: &'a str) -> Self {
+        Self {
+            value,
+            label,
+            disabled: false,
+        }
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// `content` is shown in the list, `label` is put into the input once chosen.
+    pub fn build(self, content: Markup) -> Markup {
+        let option_classes = "relative flex w-full cursor-default select-none items-center rounded-sm px-2 py-1.5 text-sm outline-none hover:bg-accent hover:text-accent-foreground data-[highlighted]:bg-accent data-[highlighted]:text-accent-foreground aria-disabled:pointer-events-none aria-disabled:opacity-50";
+
+        html! {
+            div
+                class=(option_classes)
+                role="option"
+                data-value=(self.value)
+                data-label=(self.label)
+                aria-selected="false"
+                aria-disabled=[self.disabled.then_some("true")]
+            {
+                (content)
+            }
+        }
+    }
+}
+
+pub fn ui_combobox_script() -> Markup {
+    html! {
+        script {
+            (PreEscaped(include_str!("../../scripts/combobox.js")))
+        }
+    }
+}
@@ -0,0 +1,381 @@
+use maud::{html, Markup, PreEscaped};
+
+use super::{
+    dialog::{modal_script, open_script},
+    input::{InputSize, INPUT_CLASSES},
+    with_class,
+};
+
+/// Entry of a [`Command`] list, running its action (a link with `href`, or an
+/// htmx request with `hx_get`/`hx_post`) when chosen.
+pub struct CommandItem<'a> {
+    label: &'a str,
+    keywords: Option<&'a str>,
+    icon: Option<Markup>,
+    shortcut: Option<&'a str>,
+    href: Option<&'a str>,
+    hx_get: Option<&'a str>,
+    hx_post: Option<&'a str>,
+    hx_target: Option<&'a str>,
+    hx_swap: Option<&'a str>,
+    disabled: bool,
+}
+
+impl<'a> CommandItem<'a> {
+    pub fn new(label: &'a str) -> Self {
+        Self {
+            label,
+            keywords: None,
+            icon: None,
+            shortcut: None,
+            href: None,
+            hx_get: None,
+            hx_post: None,
+            hx_target: None,
+            hx_swap: None,
+            disabled: false,
+        }
+    }
+
+    /// Other words the item is found with when filtering, e.g. `"settings preferences"`.
+    pub fn keywords(mut self, keywords: &'a str) -> Self {
+        self.keywords = Some(keywords);
+        self
+    }
+
+    /// Icon shown before the label, usually a `h-4 w-4` svg.
+    pub fn icon(mut self, icon: Markup) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// Keyboard shortcut hint shown after the label, e.g. `"⌘P"`.
+    pub fn shortcut(mut self, shortcut: &'a str) -> Self {
+        self.shortcut = Some(shortcut);
+        self
+    }
+
+    pub fn href(mut self, href: &'a str) -> Self {
+        self.href = Some(href);
+        self
+    }
+
+    pub fn hx_get(mut self, hx_get: &'a str) -> Self {
+        self.hx_get = Some(hx_get);
+        self
+    }
+
+    pub fn hx_post(mut self, hx_post: &'a str) -> Self {
+        self.hx_post = Some(hx_post);
+        self
+    }
+
+    pub fn hx_target(mut self, hx_target: &'a str) -> Self {
+        self.hx_target = Some(hx_target);
+        self
+    }
+
+    pub fn hx_swap(mut self, hx_swap: &'a str) -> Self {
+        self.hx_swap = Some(hx_swap);
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// The item alone, e.g. in the response of the [`Command`] search endpoint.
+    pub fn build(self) -> Markup {
+        command_item(&self)
+    }
+}
+
+fn command_item<'a>(item: &CommandItem<'a>) -> Markup {
+    let classes = "relative flex cursor-default select-none items-center gap-2 rounded-sm px-2 py-1.5 text-sm outline-none data-[highlighted]:bg-accent data-[highlighted]:text-accent-foreground aria-disabled:pointer-events-none aria-disabled:opacity-50 [&[hidden]]:hidden";
+    // Disabled items don't carry their action at all.
+    let enabled = |value: Option<&'a str>| value.filter(|_| !item.disabled);
+    let content = html! {
+        @if let Some(icon) = &item.icon {
+            (icon)
+        }
+        (item.label)
+        @if let Some(shortcut) = item.shortcut {
+            span class="ml-auto text-xs tracking-widest text-muted-foreground" {
+                (shortcut)
+            }
+        }
+    };
+
+    html! {
+        @if let Some(href) = enabled(item.href) {
+            a
+                class=(classes)
+                role="option"
+                aria-selected="false"
+                href=(href)
+                hx-get=[item.hx_get]
+                hx-target=[item.hx_target]
+                hx-swap=[item.hx_swap]
+                data-keywords=[item.keywords]
+                tabindex="-1"
+            {
+                (content)
+            }
+        } @else {
+            div
+                class=(classes)
+                role="option"
+                aria-selected="false"
+                aria-disabled=[item.disabled.then_some("true")]
+                hx-get=[enabled(item.hx_get)]
+                hx-post=[enabled(item.hx_post)]
+                hx-target=[item.hx_target]
+                hx-swap=[item.hx_swap]
+                data-keywords=[item.keywords]
+            {
+                (content)
+            }
+        }
+    }
+}
+
+/// Items under a heading in a [`Command`] list.
+#[derive(Default)]
+pub struct CommandGroup<'a> {
+    heading: Option<&'a str>,
+    items: Vec<CommandItem<'a>>,
+}
+
+impl<'a> CommandGroup<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn heading(mut self, heading: &'a str) -> Self {
+        self.heading = Some(heading);
+        self
+    }
+
+    pub fn item(mut self, item: CommandItem<'a>) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    /// The group alone, e.g. in the response of the [`Command`] search endpoint.
+    pub fn build(self) -> Markup {
+        command_group(&self)
+    }
+}
+
+fn command_group(group: &CommandGroup) -> Markup {
+    html! {
+        div class="overflow-hidden p-1 text-foreground" role="group" aria-label=[group.heading] data-command-group {
+            @if let Some(heading) = group.heading {
+                div class="px-2 py-1.5 text-xs font-medium text-muted-foreground" aria-hidden="true" {
+                    (heading)
+                }
+            }
+            @for item in &group.items {
+                (command_item(item))
+            }
+        }
+    }
+}
+
+enum CommandEntry<'a> {
+    Group(CommandGroup<'a>),
+    Separator,
+}
+
+/// Searchable list of actions, navigable with the keyboard.
+///
+/// Typing filters the items given here on the client. When `hx_get` is set,
+/// the text is also sent as `GET {hx_get}?{search_param}={text}` and the
+/// response, usually [`CommandGroup`]s, is shown after them. Requires
+/// [`ui_command_script`] on the page.
+pub struct Command<'a> {
+    id: &'a str,
+    entries: Vec<CommandEntry<'a>>,
+    placeholder: &'a str,
+    empty: &'a str,
+    hx_get: Option<&'a str>,
+    search_param: &'a str,
+    class: Option<&'a str>,
+}
+
+impl<'a> Command<'a> {
+    pub fn new(id: &'a str) -> Self {
+        Self {
+            id,
+            entries: Vec::new(),
+            placeholder: "Type a command or search...",
+            empty: "No results found.",
+            hx_get: None,
+            search_param: "q",
+            class: None,
+        }
+    }
+
+    pub fn group(mut self, group: CommandGroup<'a>) -> Self {
+        self.entries.push(CommandEntry::Group(group));
+        self
+    }
+
+    pub fn separator(mut self) -> Self {
+        self.entries.push(CommandEntry::Separator);
+        self
+    }
+
+    pub fn placeholder(mut self, placeholder: &'a str) -> Self {
+        self.placeholder = placeholder;
+        self
+    }
+
+    /// Message shown when no item matches.
+    pub fn empty(mut self, empty: &'a str) -> Self {
+        self.empty = empty;
+        self
+    }
+
+    pub fn hx_get(mut self, hx_get: &'a str) -> Self {
+        self.hx_get = Some(hx_get);
+        self
+    }
+
+    /// Query parameter carrying the search text, defaults to `q`.
+    pub fn search_param(mut self, search_param: &'a str) -> Self {
+        self.search_param = search_param;
+        self
+    }
+
+    pub fn class(mut self, class: &'a str) -> Self {
+        self.class = Some(class);
+        self
+    }
+
+    pub fn build(self) -> Markup {
+        command(self)
+    }
+}
+
+fn command(props: Command) -> Markup {
+    let base_classes =
+        "flex h-full w-full flex-col overflow-hidden rounded-md bg-popover text-popover-foreground";
+    let input_id = format!("{}-input", props.id);
+    let list_id = format!("{}-list", props.id);
+    let results_id = format!("{}-results", props.id);
+
+    html! {
+        div class=(with_class(base_classes, props.class)) id=(props.id) data-command {
+            div class="relative border-b border-border p-2" {
+                svg class="pointer-events-none absolute left-5 top-1/2 h-4 w-4 -translate-y-1/2 opacity-50" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round" viewBox="0 0 24 24" xmlns="http://www.w3.org/2000/svg" aria-hidden="true" {
+                    circle cx="11" cy="11" r="8" {}
+                    path d="m21 21-4.3-4.3" {}
+                }
+                input
+                    class=(format!("{} {} pl-9", INPUT_CLASSES, InputSize::Default.classes()))
+                    type="text"
+                    role="combobox"
+                    id=(input_id)
+                    name=(props.search_param)
+                    placeholder=(props.placeholder)
+                    aria-label=(props.placeholder)
+                    autocomplete="off"
+                    spellcheck="false"
+                    aria-autocomplete="list"
+                    aria-expanded="true"
+                    aria-controls=(list_id)
+                    hx-get=[props.hx_get]
+                    hx-trigger=[props.hx_get.map(|_| "input changed delay:200ms")]
+                    hx-target=[props.hx_get.map(|_| format!("#{}", results_id))] {}
+            }
+            div class="max-h-[300px] overflow-y-auto overflow-x-hidden" role="listbox" id=(list_id) {
+                div class="py-6 text-center text-sm" hidden data-command-empty {
+                    (props.empty)
+                }
+                @for entry in &props.entries {
+                    @match entry {
+                        CommandEntry::Group(group) => (command_group(group)),
+                        CommandEntry::Separator => div class="-mx-1 h-px bg-border" role="separator" {},
+                    }
+                }
+                @if props.hx_get.is_some() {
+                    div id=(results_id) data-command-results {}
+                }
+            }
+        }
+    }
+}
+
+/// [`Command`] in a modal `<dialog>`, also opened with Ctrl+`shortcut` (⌘ on
+/// macOS) from anywhere on the page.
+pub struct CommandDialog<'a> {
+    id: &'a str,
+    command: Command<'a>,
+    trigger: Option<Markup>,
+    shortcut: char,
+    class: Option<&'a str>,
+}
+
+impl<'a> CommandDialog<'a> {
+    pub fn new(id: &'a str, command: Command<'a>) -> Self {
+        Self {
+            id,
+            command,
+            trigger: None,
+            shortcut: 'k',
+            class: None,
+        }
+    }
+
+    /// Element that opens the dialog when clicked, usually a [`Button`](super::button::Button).
+    pub fn trigger(mut self, trigger: Markup) -> Self {
+        self.trigger = Some(trigger);
+        self
+    }
+
+    /// Key opening the dialog with Ctrl or ⌘, defaults to `k`.
+    pub fn shortcut(mut self, shortcut: char) -> Self {
+        self.shortcut = shortcut;
+        self
+    }
+
+    pub fn class(mut self, class: &'a str) -> Self {
+        self.class = Some(class);
+        self
+    }
+
+    pub fn build(self) -> Markup {
+        command_dialog(self)
+    }
+}
+
+fn command_dialog(props: CommandDialog) -> Markup {
+    let base_classes = "w-full max-w-lg overflow-hidden border border-border bg-popover p-0 text-popover-foreground shadow-lg sm:rounded-lg backdrop:bg-black/80";
+
+    html! {
+        @if let Some(trigger) = props.trigger {
+            span class="contents" _=(open_script(props.id)) {
+                (trigger)
+            }
+        }
+        dialog
+            id=(props.id)
+            class=(with_class(base_classes, props.class))
+            aria-label="Command palette"
+            data-command-dialog=(props.shortcut.to_ascii_lowercase())
+            _=(modal_script(false))
+        {
+            (props.command.build())
+        }
+    }
+}
+
+pub fn ui_command_script() -> Markup {
+    html! {
+        script {
+            (PreEscaped(include_str!("../../scripts/command.js")))
+        }
+    }
+}
@@ -0,0 +1,363 @@
+use maud::{html, Markup};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    button::{Button, ButtonSize},
+    input::INPUT_CLASSES,
+    pagination::Pagination,
+    table::{Table, TableBody, TableCell, TableHead, TableHeader, TableRow},
+    with_class,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SortOrder {
+    #[default]
+    Asc,
+    Desc,
+}
+
+/// Sorting, filtering and paging state of a [`DataTable`], read from the query
+/// string with `axum::extract::Query<DataTableQuery>`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct DataTableQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<String>,
+    pub order: SortOrder,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub q: Option<String>,
+    /// 1-based page number.
+    pub page: usize,
+}
+
+impl Default for DataTableQuery {
+    fn default() -> Self {
+        Self {
+            sort: None,
+            order: SortOrder::Asc,
+            q: None,
+            page: 1,
+        }
+    }
+}
+
+impl DataTableQuery {
+    /// Link to `base_url` with this state as query string.
+    pub fn href(&self, base_url: &str) -> String {
+        let query = serde_urlencoded::to_string(self).expect("urlencode data table query");
+        format!("{}?{}", base_url, query)
+    }
+
+    /// Index of the first row of the current page.
+    pub fn offset(&self, page_size: usize) -> usize {
+        self.page.saturating_sub(1) * page_size
+    }
+}
+
+pub struct DataTableColumn<'a, T> {
+    key: &'a str,
+    header: &'a str,
+    cell: Box<dyn Fn(&T) -> Markup + 'a>,
+    sortable: bool,
+    class: Option<&'a str>,
+}
+
+impl<'a, T> DataTableColumn<'a, T> {
+    /// `key` is sent as the `sort` query parameter when sorting by this column.
+    pub fn new(key: &'a str, header: &'a str, cell: impl Fn(&T) -> Markup + 'a) -> Self {
+        Self {
+            key,
+            header,
+            cell: Box::new(cell),
+            sortable: false,
+            class: None,
+        }
+    }
+
+    pub fn sortable(mut self, sortable: bool) -> Self {
+        self.sortable = sortable;
+        self
+    }
+
+    /// Classes for the header and the cells of this column.
+    pub fn class(mut self, class: &'a str) -> Self {
+        self.class = Some(class);
+        self
+    }
+}
+
+/// Typed table whose sorting, filtering and paging happen on the server.
+///
+/// `rows` are the rows of the current page. Every control issues an `hx-get`
+/// to `url` with the new [`DataTableQuery`], and the response (the table
+/// rendered again) replaces this one.
+///
+/// With `push_url`, the `url` of every change is pushed into the browser
+/// history, so `url` must render the full page when the request has no
+/// `HX-Request` header (e.g. after a reload or going back).
+pub struct DataTable<'a, T> {
+    id: &'a str,
+    url: &'a str,
+    rows: &'a [T],
+    columns: Vec<DataTableColumn<'a, T>>,
+    query: DataTableQuery,
+    total: Option<usize>,
+    page_size: usize,
+    filter_placeholder: Option<&'a str>,
+    empty: &'a str,
+    push_url: bool,
+    class: Option<&'a str>,
+}
+
+impl<'a, T> DataTable<'a, T> {
+    pub fn new(id: &'a str, url: &'a str, rows: &'a [T]) -> Self {
+        Self {
+            id,
+            url,
+            rows,
+            columns: Vec::new(),
+            query: DataTableQuery::default(),
+            total: None,
+            page_size: 10,
+            filter_placeholder: None,
+            empty: "No results.",
+            push_url: false,
+            class: None,
+        }
+    }
+
+    pub fn column(mut self, column: DataTableColumn<'a, T>) -> Self {
+        self.columns.push(column);
+        self
+    }
+
+    pub fn query(mut self, query: DataTableQuery) -> Self {
+        self.query = query;
+        self
+    }
+
+    /// Total number of rows across all pages, enables the pagination controls.
+    pub fn total(mut self, total: usize) -> Self {
+        self.total = Some(total);
+        self
+    }
+
+    pub fn page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Show a filter input sending its text as the `q` query parameter.
+    pub fn filter(mut self, placeholder: &'a str) -> Self {
+        self.filter_placeholder = Some(placeholder);
+        self
+    }
+
+    /// Message shown when there are no rows.
+    pub fn empty(mut self, empty: &'a str) -> Self {
+        self.empty = empty;
+        self
+    }
+
+    /// Push the `url` of every change into the browser history, see [`DataTable`].
+    pub fn push_url(mut self, push_url: bool) -> Self {
+        self.push_url = push_url;
+        self
+    }
+
+    pub fn class(mut self, class: &'a str) -> Self {
+        self.class = Some(class);
+        self
+    }
+
+    pub fn build(self) -> Markup {
+        data_table(self)
+    }
+}
+
+fn data_table<T>(props: DataTable<T>) -> Markup {
+    let query = &props.query;
+
+    html! {
+        div
+            id=(props.id)
+            class=(with_class("w-full space-y-4", props.class))
+            hx-target="this"
+            hx-swap="outerHTML"
+            hx-push-url=(props.push_url)
+        {
+            @if let Some(placeholder) = props.filter_placeholder {
+                form class="flex items-center" hx-get=(props.url) {
+                    @if let Some(sort) = &query.sort {
+                        input type="hidden" name="sort" value=(sort) {}
+                        input type="hidden" name="order" value=(if query.order == SortOrder::Asc { "asc" } else { "desc" }) {}
+                    }
+                    input
+                        class=(with_class(INPUT_CLASSES, Some("h-10 max-w-sm")))
+                        type="search"
+                        // htmx restores the focus and caret to the new input by its id.
+                        id=(format!("{}-filter", props.id))
+                        name="q"
+                        value=[query.q.as_deref()]
+                        placeholder=(placeholder)
+                        aria-label=(placeholder)
+                        autocomplete="off"
+                        hx-get=(props.url)
+                        hx-trigger="keyup changed delay:300ms, search"
+                        hx-include="closest form" {}
+                }
+            }
+            div class="rounded-md border border-border" {
+                (Table::new().build(html! {
+                    (TableHeader::new().build(html! {
+                        (TableRow::new().build(html! {
+                            @for column in &props.columns {
+                                (header_cell(column, query, props.url))
+                            }
+                        }))
+                    }))
+                    (TableBody::new().build(html! {
+                        @for row in props.rows {
+                            (TableRow::new().build(html! {
+                                @for column in &props.columns {
+                                    @let cell = TableCell::new();
+                                    @let cell = match column.class {
+                                        Some(class) => cell.class(class),
+                                        None => cell,
+                                    };
+                                    (cell.build((column.cell)(row)))
+                                }
+                            }))
+                        }
+                        @if props.rows.is_empty() {
+                            (TableRow::new().build(html! {
+                                (TableCell::new()
+                                    .colspan(props.columns.len().max(1))
+                                    .class("h-24 text-center text-muted-foreground")
+                                    .build(html! { (props.empty) }))
+                            }))
+                        }
+                    }))
+                }))
+            }
+            @if let Some(total) = props.total {
+                (page_controls(query, props.url, total, props.page_size, props.push_url))
+            }
+        }
+    }
+}
+
+fn header_cell<T>(column: &DataTableColumn<T>, query: &DataTableQuery, url: &str) -> Markup {
+    let head = TableHead::new();
+    let head = match column.class {
+        Some(class) => head.class(class),
+        None => head,
+    };
+
+    if !column.sortable {
+        return head.build(html! { (column.header) });
+    }
+
+    let sorted = query.sort.as_deref() == Some(column.key);
+    let (aria_sort, next_order) = match (sorted, query.order) {
+        (true, SortOrder::Asc) => ("ascending", SortOrder::Desc),
+        (true, SortOrder::Desc) => ("descending", SortOrder::Asc),
+        (false, _) => ("none", SortOrder::Asc),
+    };
+    let href = DataTableQuery {
+        sort: Some(column.key.to_string()),
+        order: next_order,
+        page: 1,
+        ..query.clone()
+    }
+    .href(url);
+
+    head.aria_sort(aria_sort).build(
+        Button::ghost()
+            .size(ButtonSize::Sm)
+            .ty("button")
+            .class("-ml-3 h-8 gap-2")
+            .hx_get(&href)
+            .build(html! {
+                (column.header)
+                svg class="h-4 w-4" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round" viewBox="0 0 24 24" xmlns="http://www.w3.org/2000/svg" aria-hidden="true" {
+                    @match aria_sort {
+                        "ascending" => path d="m18 15-6-6-6 6" {},
+                        "descending" => path d="m6 9 6 6 6-6" {},
+                        _ => {
+                            path d="m7 15 5 5 5-5" {}
+                            path d="m7 9 5-5 5 5" {}
+                        },
+                    }
+                }
+            }),
+    )
+}
+
+fn page_controls(
+    query: &DataTableQuery,
+    url: &str,
+    total: usize,
+    page_size: usize,
+    push_url: bool,
+) -> Markup {
+    let pagination = Pagination::from_items(query.page, total, page_size);
+    let total_pages = total.div_ceil(page_size.max(1)).max(1);
+
+    html! {
+        div class="flex items-center justify-end gap-2" {
+            span class="flex-1 whitespace-nowrap text-sm text-muted-foreground" {
+                "Page " (query.page.clamp(1, total_pages)) " of " (total_pages)
+            }
+            // Targets the table through the inherited `hx-target`.
+            (pagination
+                .href(|page| DataTableQuery { page, ..query.clone() }.href(url))
+                .hx_get(true)
+                .push_url(push_url)
+                .class("mx-0 w-auto justify-end")
+                .build())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DataTableQuery, SortOrder};
+
+    fn round_trip(query: &DataTableQuery) -> DataTableQuery {
+        let href = query.href("/users");
+        let (path, query_string) = href.split_once('?').unwrap();
+        assert_eq!(path, "/users");
+        serde_urlencoded::from_str(query_string).unwrap()
+    }
+
+    #[test]
+    fn href_round_trips() {
+        let query = DataTableQuery {
+            sort: Some("email".to_string()),
+            order: SortOrder::Desc,
+            q: Some("a&b c=d".to_string()),
+            page: 3,
+        };
+        assert_eq!(round_trip(&query), query);
+    }
+
+    #[test]
+    fn href_skips_empty_fields() {
+        let query = DataTableQuery::default();
+        assert_eq!(query.href("/users"), "/users?order=asc&page=1");
+        assert_eq!(round_trip(&query), query);
+    }
+
+    #[test]
+    fn offset() {
+        let page = |page| DataTableQuery {
+            page,
+            ..DataTableQuery::default()
+        };
+        assert_eq!(page(0).offset(10), 0);
+        assert_eq!(page(1).offset(10), 0);
+        assert_eq!(page(3).offset(10), 20);
+    }
+}
@@ -0,0 +1,204 @@
+use maud::{html, Markup};
+
+use super::with_class;
+
+/// Modal dialog rendered as a native `<dialog>` element.
+///
+/// The dialog is opened with `showModal()`, so the browser takes care of
+/// trapping focus, making the rest of the page inert and closing on Escape.
+/// When `hx_get` is set, the body is (re)loaded from the server every time the
+/// dialog is opened and the `build` content is shown while loading.
+pub struct Dialog<'a> {
+    id: &'a str,
+    trigger: Option<Markup>,
+    title: Option<&'a str>,
+    description: Option<&'a str>,
+    footer: Option<Markup>,
+    hx_get: Option<&'a str>,
+    open: bool,
+    close_button: bool,
+    class: Option<&'a str>,
+}
+
+impl<'a> Dialog<'a> {
+    pub fn new(id: &'a str) -> Self {
+        Self {
+            id,
+            trigger: None,
+            title: None,
+            description: None,
+            footer: None,
+            hx_get: None,
+            open: false,
+            close_button: true,
+            class: None,
+        }
+    }
+
+    /// Element that opens the dialog when clicked, usually a [`Button`](super::button::Button).
+    pub fn trigger(mut self, trigger: Markup) -> Self {
+        self.trigger = Some(trigger);
+        self
+    }
+
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    pub fn description(mut self, description: &'a str) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    pub fn footer(mut self, footer: Markup) -> Self {
+        self.footer = Some(footer);
+        self
+    }
+
+    pub fn hx_get(mut self, hx_get: &'a str) -> Self {
+        self.hx_get = Some(hx_get);
+        self
+    }
+
+    /// Open the dialog as soon as it is rendered, e.g. when it is swapped in by htmx.
+    pub fn open(mut self, open: bool) -> Self {
+        self.open = open;
+        self
+    }
+
+    pub fn close_button(mut self, close_button: bool) -> Self {
+        self.close_button = close_button;
+        self
+    }
+
+    pub fn class(mut self, class: &'a str) -> Self {
+        self.class = Some(class);
+        self
+    }
+
+    pub fn build(self, content: Markup) -> Markup {
+        dialog(self, content)
+    }
+}
+
+fn dialog(props: Dialog, content: Markup) -> Markup {
+    let base_classes = "w-full max-w-lg border border-border bg-popover p-0 text-popover-foreground shadow-lg sm:rounded-lg backdrop:bg-black/80";
+    let classes = with_class(base_classes, props.class);
+
+    let title_id = format!("{}-title", props.id);
+    let description_id = format!("{}-description", props.id);
+    let body_id = format!("{}-body", props.id);
+
+    html! {
+        @if let Some(trigger) = props.trigger {
+            span class="contents" _=(open_and_load_script(props.id, props.hx_get)) {
+                (trigger)
+            }
+        }
+        dialog
+            id=(props.id)
+            class=(classes)
+            role="dialog"
+            aria-modal="true"
+            aria-labelledby=[props.title.map(|_| &title_id)]
+            aria-describedby=[props.description.map(|_| &description_id)]
+            _=(modal_script(props.open))
+        {
+            div class="relative grid gap-4 p-6" {
+                @if props.title.is_some() || props.description.is_some() {
+                    (header(&title_id, props.title, &description_id, props.description))
+                }
+                div
+                    id=(body_id)
+                    hx-get=[props.hx_get]
+                    hx-trigger=[props.hx_get.map(|_| "dialog:open")]
+                {
+                    (content)
+                }
+                @if let Some(footer_content) = props.footer {
+                    (footer(footer_content))
+                }
+                @if props.close_button {
+                    (close_button(props.id))
+                }
+            }
+        }
+    }
+}
+
+/// Hyperscript of a modal `<dialog>`, closed by clicking the backdrop and
+/// opened as soon as it is rendered with `open`.
+pub(crate) fn modal_script(open: bool) -> String {
+    // Clicking the backdrop targets the `<dialog>` itself, clicks inside hit the inner wrapper.
+    let mut hyperscript = String::from("on click if event.target is me call me.close() end end");
+    if open {
+        hyperscript.push_str(" init call me.showModal()");
+    }
+    hyperscript
+}
+
+/// Hyperscript opening the dialog `id`.
+pub(crate) fn open_script(id: &str) -> String {
+    format!("on click call #{id}.showModal()")
+}
+
+/// Hyperscript opening the dialog `id` and, with `hx_get`, loading its
+/// server-rendered body `{id}-body`.
+pub(crate) fn open_and_load_script(id: &str, hx_get: Option<&str>) -> String {
+    match hx_get {
+        Some(_) => format!("{} then send dialog:open to #{id}-body", open_script(id)),
+        None => open_script(id),
+    }
+}
+
+/// Hyperscript closing the dialog `id`.
+pub(crate) fn close_script(id: &str) -> String {
+    format!("on click call #{id}.close()")
+}
+
+pub(crate) fn header(
+    title_id: &str,
+    title: Option<&str>,
+    description_id: &str,
+    description: Option<&str>,
+) -> Markup {
+    html! {
+        div class="flex flex-col space-y-1.5 text-center sm:text-left" {
+            @if let Some(title) = title {
+                h2 id=(title_id) class="text-lg font-semibold leading-none tracking-tight" {
+                    (title)
+                }
+            }
+            @if let Some(description) = description {
+                p id=(description_id) class="text-sm text-muted-foreground" {
+                    (description)
+                }
+            }
+        }
+    }
+}
+
+pub(crate) fn footer(content: Markup) -> Markup {
+    html! {
+        div class="flex flex-col-reverse gap-2 sm:flex-row sm:justify-end" {
+            (content)
+        }
+    }
+}
+
+pub(crate) fn close_button(id: &str) -> Markup {
+    html! {
+        button
+            type="button"
+            class="absolute right-4 top-4 rounded-sm opacity-70 ring-offset-background transition-opacity hover:opacity-100 focus:outline-none focus:ring-2 focus:ring-ring focus:ring-offset-2"
+            aria-label="Close"
+            _=(close_script(id))
+        {
+            svg class="h-4 w-4" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round" viewBox="0 0 24 24" xmlns="http://www.w3.org/2000/svg" {
+                path d="M18 6 6 18" {}
+                path d="m6 6 12 12" {}
+            }
+        }
+    }
+}
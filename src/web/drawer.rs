@@ -0,0 +1,159 @@
+use maud::{html, Markup, PreEscaped};
+
+use super::{
+    dialog::{header, modal_script, open_and_load_script},
+    with_class,
+};
+
+/// Bottom panel for touch devices, dragged by its handle between `snap_points`
+/// and swiped down to be dismissed.
+///
+/// Without [`ui_drawer_script`] on the page it is a plain modal `<dialog>`
+/// anchored to the bottom of the screen, closed on Escape or on a click on the
+/// overlay. When `hx_get` is set, the body is (re)loaded from the server every
+/// time the drawer is opened.
+pub struct Drawer<'a> {
+    id: &'a str,
+    trigger: Option<Markup>,
+    title: Option<&'a str>,
+    description: Option<&'a str>,
+    footer: Option<Markup>,
+    hx_get: Option<&'a str>,
+    snap_points: &'a [f32],
+    open: bool,
+    class: Option<&'a str>,
+}
+
+impl<'a> Drawer<'a> {
+    pub fn new(id: &'a str) -> Self {
+        Self {
+            id,
+            trigger: None,
+            title: None,
+            description: None,
+            footer: None,
+            hx_get: None,
+            snap_points: &[],
+            open: false,
+            class: None,
+        }
+    }
+
+    /// Element that opens the drawer when clicked, usually a [`Button`](super::button::Button).
+    pub fn trigger(mut self, trigger: Markup) -> Self {
+        self.trigger = Some(trigger);
+        self
+    }
+
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    pub fn description(mut self, description: &'a str) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    pub fn footer(mut self, footer: Markup) -> Self {
+        self.footer = Some(footer);
+        self
+    }
+
+    pub fn hx_get(mut self, hx_get: &'a str) -> Self {
+        self.hx_get = Some(hx_get);
+        self
+    }
+
+    /// Heights the drawer rests at, as fractions of the viewport height in
+    /// ascending order (e.g. `&[0.5, 1.0]`). It opens at the first one. By
+    /// default the drawer takes the height of its content.
+    pub fn snap_points(mut self, snap_points: &'a [f32]) -> Self {
+        self.snap_points = snap_points;
+        self
+    }
+
+    /// Open the drawer as soon as it is rendered, e.g. when it is swapped in by htmx.
+    pub fn open(mut self, open: bool) -> Self {
+        self.open = open;
+        self
+    }
+
+    pub fn class(mut self, class: &'a str) -> Self {
+        self.class = Some(class);
+        self
+    }
+
+    pub fn build(self, content: Markup) -> Markup {
+        drawer(self, content)
+    }
+}
+
+fn drawer(props: Drawer, content: Markup) -> Markup {
+    // Overrides the centering and size limits of the `<dialog>` default styles.
+    let base_classes = "fixed inset-x-0 bottom-0 top-auto m-0 h-auto max-h-[96dvh] w-full max-w-none rounded-t-[10px] border border-border bg-background p-0 text-foreground backdrop:bg-black/80 open:flex open:flex-col open:animate-sheet-in-bottom";
+    let classes = with_class(base_classes, props.class);
+
+    let title_id = format!("{}-title", props.id);
+    let description_id = format!("{}-description", props.id);
+    let body_id = format!("{}-body", props.id);
+    let snap_points = (!props.snap_points.is_empty()).then(|| {
+        props
+            .snap_points
+            .iter()
+            .map(f32::to_string)
+            .collect::<Vec<_>>()
+            .join(",")
+    });
+
+    html! {
+        @if let Some(trigger) = props.trigger {
+            span class="contents" _=(open_and_load_script(props.id, props.hx_get)) {
+                (trigger)
+            }
+        }
+        dialog
+            id=(props.id)
+            class=(classes)
+            role="dialog"
+            aria-modal="true"
+            aria-labelledby=[props.title.map(|_| &title_id)]
+            aria-describedby=[props.description.map(|_| &description_id)]
+            data-drawer
+            data-drawer-snap-points=[snap_points]
+            _=(modal_script(props.open))
+        {
+            div class="flex min-h-0 flex-1 flex-col" {
+                div class="cursor-grab touch-none select-none active:cursor-grabbing" data-drawer-handle {
+                    div class="mx-auto mt-4 h-2 w-[100px] rounded-full bg-muted" {}
+                    @if props.title.is_some() || props.description.is_some() {
+                        div class="p-4" {
+                            (header(&title_id, props.title, &description_id, props.description))
+                        }
+                    }
+                }
+                div
+                    class="flex-1 overflow-y-auto p-4"
+                    id=(body_id)
+                    hx-get=[props.hx_get]
+                    hx-trigger=[props.hx_get.map(|_| "dialog:open")]
+                {
+                    (content)
+                }
+                @if let Some(footer) = props.footer {
+                    div class="mt-auto flex flex-col gap-2 p-4" {
+                        (footer)
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn ui_drawer_script() -> Markup {
+    html! {
+        script {
+            (PreEscaped(include_str!("../../scripts/drawer.js")))
+        }
+    }
+}
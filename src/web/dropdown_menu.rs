@@ -0,0 +1,589 @@
+use maud::{html, Markup, PreEscaped};
+
+use super::{
+    button::{Button, ButtonSize},
+    floating::{Align, Placement, Side},
+    menu::MenuEntry,
+    with_class,
+};
+
+const ITEM_CLASSES: &str = "relative flex cursor-default select-none items-center gap-2 rounded-sm px-2 py-1.5 text-sm outline-none transition-colors focus:bg-accent focus:text-accent-foreground aria-disabled:pointer-events-none aria-disabled:opacity-50";
+const INDICATOR_ITEM_CLASSES: &str = "group relative flex cursor-default select-none items-center rounded-sm py-1.5 pl-8 pr-2 text-sm outline-none transition-colors focus:bg-accent focus:text-accent-foreground aria-disabled:pointer-events-none aria-disabled:opacity-50";
+const CONTENT_CLASSES: &str = "z-50 min-w-[8rem] rounded-md border border-border bg-popover p-1 text-popover-foreground shadow-md focus:outline-none";
+
+/// Menu item running an action: a link with `href`, or an htmx request with
+/// `hx_get`/`hx_post`.
+pub struct DropdownMenuItem<'a> {
+    label: &'a str,
+    icon: Option<Markup>,
+    shortcut: Option<&'a str>,
+    href: Option<&'a str>,
+    hx_get: Option<&'a str>,
+    hx_post: Option<&'a str>,
+    hx_target: Option<&'a str>,
+    hx_swap: Option<&'a str>,
+    disabled: bool,
+}
+
+impl<'a> DropdownMenuItem<'a> {
+    pub fn new(label: &'a str) -> Self {
+        Self {
+            label,
+            icon: None,
+            shortcut: None,
+            href: None,
+            hx_get: None,
+            hx_post: None,
+            hx_target: None,
+            hx_swap: None,
+            disabled: false,
+        }
+    }
+
+    /// Icon shown before the label, usually a `h-4 w-4` svg.
+    pub fn icon(mut self, icon: Markup) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// Keyboard shortcut hint shown after the label, e.g. `"⌘E"`.
+    pub fn shortcut(mut self, shortcut: &'a str) -> Self {
+        self.shortcut = Some(shortcut);
+        self
+    }
+
+    pub fn href(mut self, href: &'a str) -> Self {
+        self.href = Some(href);
+        self
+    }
+
+    pub fn hx_get(mut self, hx_get: &'a str) -> Self {
+        self.hx_get = Some(hx_get);
+        self
+    }
+
+    pub fn hx_post(mut self, hx_post: &'a str) -> Self {
+        self.hx_post = Some(hx_post);
+        self
+    }
+
+    pub fn hx_target(mut self, hx_target: &'a str) -> Self {
+        self.hx_target = Some(hx_target);
+        self
+    }
+
+    pub fn hx_swap(mut self, hx_swap: &'a str) -> Self {
+        self.hx_swap = Some(hx_swap);
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+/// Item toggled on click. With `name` and `hx_post`, the new state is posted
+/// as `name=true` or `name=false`.
+pub struct DropdownMenuCheckboxItem<'a> {
+    label: &'a str,
+    name: Option<&'a str>,
+    checked: bool,
+    shortcut: Option<&'a str>,
+    hx_post: Option<&'a str>,
+    hx_target: Option<&'a str>,
+    hx_swap: Option<&'a str>,
+    disabled: bool,
+}
+
+impl<'a> DropdownMenuCheckboxItem<'a> {
+    pub fn new(label: &'a str) -> Self {
+        Self {
+            label,
+            name: None,
+            checked: false,
+            shortcut: None,
+            hx_post: None,
+            hx_target: None,
+            hx_swap: None,
+            disabled: false,
+        }
+    }
+
+    pub fn name(mut self, name: &'a str) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    pub fn checked(mut self, checked: bool) -> Self {
+        self.checked = checked;
+        self
+    }
+
+    pub fn shortcut(mut self, shortcut: &'a str) -> Self {
+        self.shortcut = Some(shortcut);
+        self
+    }
+
+    pub fn hx_post(mut self, hx_post: &'a str) -> Self {
+        self.hx_post = Some(hx_post);
+        self
+    }
+
+    pub fn hx_target(mut self, hx_target: &'a str) -> Self {
+        self.hx_target = Some(hx_target);
+        self
+    }
+
+    pub fn hx_swap(mut self, hx_swap: &'a str) -> Self {
+        self.hx_swap = Some(hx_swap);
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+pub struct DropdownMenuRadioItem<'a> {
+    value: &'a str,
+    label: &'a str,
+    disabled: bool,
+}
+
+impl<'a> DropdownMenuRadioItem<'a> {
+    pub fn new(value: &'a str, label: &'a str) -> Self {
+        Self {
+            value,
+            label,
+            disabled: false,
+        }
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+/// Items of which only one is checked. With `hx_post`, choosing an item posts
+/// `name={value}`.
+pub struct DropdownMenuRadioGroup<'a> {
+    name: &'a str,
+    value: Option<&'a str>,
+    items: Vec<DropdownMenuRadioItem<'a>>,
+    hx_post: Option<&'a str>,
+    hx_target: Option<&'a str>,
+    hx_swap: Option<&'a str>,
+}
+
+impl<'a> DropdownMenuRadioGroup<'a> {
+    pub fn new(name: &'a str) -> Self {
+        Self {
+            name,
+            value: None,
+            items: Vec::new(),
+            hx_post: None,
+            hx_target: None,
+            hx_swap: None,
+        }
+    }
+
+    /// Value of the checked item.
+    pub fn value(mut self, value: &'a str) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    pub fn item(mut self, item: DropdownMenuRadioItem<'a>) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    pub fn hx_post(mut self, hx_post: &'a str) -> Self {
+        self.hx_post = Some(hx_post);
+        self
+    }
+
+    pub fn hx_target(mut self, hx_target: &'a str) -> Self {
+        self.hx_target = Some(hx_target);
+        self
+    }
+
+    pub fn hx_swap(mut self, hx_swap: &'a str) -> Self {
+        self.hx_swap = Some(hx_swap);
+        self
+    }
+}
+
+/// Item opening a nested menu.
+pub struct DropdownMenuSub<'a> {
+    label: &'a str,
+    icon: Option<Markup>,
+    content: DropdownMenuContent<'a>,
+    disabled: bool,
+}
+
+impl<'a> DropdownMenuSub<'a> {
+    pub fn new(label: &'a str, content: DropdownMenuContent<'a>) -> Self {
+        Self {
+            label,
+            icon: None,
+            content,
+            disabled: false,
+        }
+    }
+
+    pub fn icon(mut self, icon: Markup) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+enum DropdownMenuEntry<'a> {
+    Item(DropdownMenuItem<'a>),
+    CheckboxItem(DropdownMenuCheckboxItem<'a>),
+    RadioGroup(DropdownMenuRadioGroup<'a>),
+    Label(&'a str),
+    Separator,
+    Sub(DropdownMenuSub<'a>),
+}
+
+/// Entries of a [`DropdownMenu`] or of a [`DropdownMenuSub`].
+#[derive(Default)]
+pub struct DropdownMenuContent<'a> {
+    entries: Vec<DropdownMenuEntry<'a>>,
+    class: Option<&'a str>,
+}
+
+impl<'a> DropdownMenuContent<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Links of `entries`, nested menus becoming submenus.
+    pub fn from_entries(entries: &[MenuEntry<'a>]) -> Self {
+        entries
+            .iter()
+            .fold(Self::new(), |content, entry| match entry {
+                MenuEntry::Link { label, href, .. } => {
+                    content.item(DropdownMenuItem::new(label).href(href))
+                }
+                MenuEntry::Menu { label, entries } => {
+                    content.submenu(DropdownMenuSub::new(label, Self::from_entries(entries)))
+                }
+                MenuEntry::Separator => content.separator(),
+            })
+    }
+
+    pub fn item(mut self, item: DropdownMenuItem<'a>) -> Self {
+        self.entries.push(DropdownMenuEntry::Item(item));
+        self
+    }
+
+    pub fn checkbox_item(mut self, item: DropdownMenuCheckboxItem<'a>) -> Self {
+        self.entries.push(DropdownMenuEntry::CheckboxItem(item));
+        self
+    }
+
+    pub fn radio_group(mut self, group: DropdownMenuRadioGroup<'a>) -> Self {
+        self.entries.push(DropdownMenuEntry::RadioGroup(group));
+        self
+    }
+
+    pub fn label(mut self, label: &'a str) -> Self {
+        self.entries.push(DropdownMenuEntry::Label(label));
+        self
+    }
+
+    pub fn separator(mut self) -> Self {
+        self.entries.push(DropdownMenuEntry::Separator);
+        self
+    }
+
+    pub fn submenu(mut self, sub: DropdownMenuSub<'a>) -> Self {
+        self.entries.push(DropdownMenuEntry::Sub(sub));
+        self
+    }
+
+    pub fn class(mut self, class: &'a str) -> Self {
+        self.class = Some(class);
+        self
+    }
+}
+
+/// Menu of actions opened from a [`Button`], e.g. the actions of a table row.
+///
+/// The menu follows the WAI-ARIA menu button pattern: arrow keys, Home/End and
+/// typeahead move the focus through the items, submenus open with ArrowRight
+/// or on hover. Requires [`ui_dropdown_menu_script`] and
+/// [`ui_floating_script`](super::floating::ui_floating_script) on the page.
+pub struct DropdownMenu<'a> {
+    id: &'a str,
+    trigger: Option<(Button<'a>, Markup)>,
+    content: DropdownMenuContent<'a>,
+    placement: Placement,
+    class: Option<&'a str>,
+}
+
+impl<'a> DropdownMenu<'a> {
+    pub fn new(id: &'a str) -> Self {
+        Self {
+            id,
+            trigger: None,
+            content: DropdownMenuContent::new(),
+            placement: Placement::new(Side::Bottom, Align::Start, 4),
+            class: None,
+        }
+    }
+
+    /// Button opening the menu, defaults to a ghost icon button with an
+    /// ellipsis.
+    pub fn trigger(mut self, button: Button<'a>, content: Markup) -> Self {
+        self.trigger = Some((button, content));
+        self
+    }
+
+    pub fn content(mut self, content: DropdownMenuContent<'a>) -> Self {
+        self.content = content;
+        self
+    }
+
+    pub fn side(mut self, side: Side) -> Self {
+        self.placement.side = side;
+        self
+    }
+
+    pub fn align(mut self, align: Align) -> Self {
+        self.placement.align = align;
+        self
+    }
+
+    /// Distance from the trigger in pixels.
+    pub fn side_offset(mut self, side_offset: u32) -> Self {
+        self.placement.side_offset = side_offset;
+        self
+    }
+
+    pub fn class(mut self, class: &'a str) -> Self {
+        self.class = Some(class);
+        self
+    }
+
+    pub fn build(self) -> Markup {
+        dropdown_menu(self)
+    }
+}
+
+fn dropdown_menu(props: DropdownMenu) -> Markup {
+    let trigger_id = format!("{}-trigger", props.id);
+    let content_id = format!("{}-content", props.id);
+
+    let (button, trigger_content) = props.trigger.unwrap_or_else(|| {
+        (
+            Button::ghost().size(ButtonSize::Icon).aria_label("Open menu"),
+            html! {
+                svg class="h-4 w-4" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round" viewBox="0 0 24 24" xmlns="http://www.w3.org/2000/svg" aria-hidden="true" {
+                    circle cx="12" cy="12" r="1" {}
+                    circle cx="19" cy="12" r="1" {}
+                    circle cx="5" cy="12" r="1" {}
+                }
+            },
+        )
+    });
+
+    html! {
+        div class=(with_class("inline-block text-left", props.class)) id=(props.id) data-dropdown-menu {
+            (button
+                .ty("button")
+                .id(&trigger_id)
+                .popup("menu", &content_id)
+                .build(trigger_content))
+            (menu(&props.content, &content_id, &trigger_id, props.placement))
+        }
+    }
+}
+
+/// `role="menu"` element with `content`, shown by [`ui_dropdown_menu_script`]
+/// when the element with `aria-controls` set to `id` is clicked.
+pub(crate) fn menu(
+    content: &DropdownMenuContent,
+    id: &str,
+    labelledby: &str,
+    placement: Placement,
+) -> Markup {
+    html! {
+        div
+            class=(with_class(CONTENT_CLASSES, content.class))
+            role="menu"
+            id=(id)
+            aria-labelledby=(labelledby)
+            tabindex="-1"
+            hidden
+            data-floating
+            data-floating-side=(placement.side.as_str())
+            data-floating-align=(placement.align.as_str())
+            data-floating-offset=(placement.side_offset)
+        {
+            @for (index, entry) in content.entries.iter().enumerate() {
+                @match entry {
+                    DropdownMenuEntry::Item(item) => (menu_item(item)),
+                    DropdownMenuEntry::CheckboxItem(item) => (checkbox_item(item)),
+                    DropdownMenuEntry::RadioGroup(group) => (radio_group(group)),
+                    DropdownMenuEntry::Label(label) => {
+                        div class="px-2 py-1.5 text-sm font-semibold" role="presentation" {
+                            (label)
+                        }
+                    },
+                    DropdownMenuEntry::Separator => div class="-mx-1 my-1 h-px bg-muted" role="separator" {},
+                    DropdownMenuEntry::Sub(sub) => (submenu(sub, &format!("{}-{}", id, index))),
+                }
+            }
+        }
+    }
+}
+
+fn shortcut(shortcut: Option<&str>) -> Markup {
+    html! {
+        @if let Some(shortcut) = shortcut {
+            span class="ml-auto pl-4 text-xs tracking-widest opacity-60" {
+                (shortcut)
+            }
+        }
+    }
+}
+
+fn menu_item<'a>(item: &DropdownMenuItem<'a>) -> Markup {
+    // Disabled items don't carry their action at all.
+    let enabled = |value: Option<&'a str>| value.filter(|_| !item.disabled);
+    let content = html! {
+        @if let Some(icon) = &item.icon {
+            (icon)
+        }
+        (item.label)
+        (shortcut(item.shortcut))
+    };
+
+    html! {
+        @if let Some(href) = enabled(item.href) {
+            a
+                class=(ITEM_CLASSES)
+                role="menuitem"
+                tabindex="-1"
+                href=(href)
+                hx-get=[item.hx_get]
+                hx-target=[item.hx_target]
+                hx-swap=[item.hx_swap]
+            {
+                (content)
+            }
+        } @else {
+            div
+                class=(ITEM_CLASSES)
+                role="menuitem"
+                tabindex="-1"
+                aria-disabled=[item.disabled.then_some("true")]
+                hx-get=[enabled(item.hx_get)]
+                hx-post=[enabled(item.hx_post)]
+                hx-target=[item.hx_target]
+                hx-swap=[item.hx_swap]
+            {
+                (content)
+            }
+        }
+    }
+}
+
+fn checkbox_item(item: &DropdownMenuCheckboxItem) -> Markup {
+    html! {
+        div
+            class=(INDICATOR_ITEM_CLASSES)
+            role="menuitemcheckbox"
+            tabindex="-1"
+            aria-checked=(item.checked)
+            aria-disabled=[item.disabled.then_some("true")]
+            data-name=[item.name]
+            hx-post=[item.hx_post.filter(|_| !item.disabled)]
+            hx-target=[item.hx_target]
+            hx-swap=[item.hx_swap]
+        {
+            span class="invisible absolute left-2 flex h-3.5 w-3.5 items-center justify-center group-aria-checked:visible" {
+                svg class="h-4 w-4" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round" viewBox="0 0 24 24" xmlns="http://www.w3.org/2000/svg" aria-hidden="true" {
+                    path d="M20 6 9 17l-5-5" {}
+                }
+            }
+            (item.label)
+            (shortcut(item.shortcut))
+        }
+    }
+}
+
+fn radio_group(group: &DropdownMenuRadioGroup) -> Markup {
+    html! {
+        div role="group" {
+            @for item in &group.items {
+                div
+                    class=(INDICATOR_ITEM_CLASSES)
+                    role="menuitemradio"
+                    tabindex="-1"
+                    aria-checked=(group.value == Some(item.value))
+                    aria-disabled=[item.disabled.then_some("true")]
+                    data-name=(group.name)
+                    data-value=(item.value)
+                    hx-post=[group.hx_post.filter(|_| !item.disabled)]
+                    hx-target=[group.hx_target]
+                    hx-swap=[group.hx_swap]
+                {
+                    span class="invisible absolute left-2 flex h-3.5 w-3.5 items-center justify-center group-aria-checked:visible" {
+                        svg class="h-2 w-2 fill-current" viewBox="0 0 24 24" xmlns="http://www.w3.org/2000/svg" aria-hidden="true" {
+                            circle cx="12" cy="12" r="10" {}
+                        }
+                    }
+                    (item.label)
+                }
+            }
+        }
+    }
+}
+
+fn submenu(sub: &DropdownMenuSub, id: &str) -> Markup {
+    let trigger_id = format!("{}-trigger", id);
+
+    html! {
+        div role="none" {
+            div
+                class=(with_class(ITEM_CLASSES, Some("aria-expanded:bg-accent")))
+                role="menuitem"
+                id=(trigger_id)
+                tabindex="-1"
+                aria-haspopup="menu"
+                aria-expanded="false"
+                aria-controls=(id)
+                aria-disabled=[sub.disabled.then_some("true")]
+            {
+                @if let Some(icon) = &sub.icon {
+                    (icon)
+                }
+                (sub.label)
+                svg class="ml-auto h-4 w-4" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round" viewBox="0 0 24 24" xmlns="http://www.w3.org/2000/svg" aria-hidden="true" {
+                    path d="m9 18 6-6-6-6" {}
+                }
+            }
+            (menu(&sub.content, id, &trigger_id, Placement::new(Side::Right, Align::Start, 4)))
+        }
+    }
+}
+
+pub fn ui_dropdown_menu_script() -> Markup {
+    html! {
+        script {
+            (PreEscaped(include_str!("../../scripts/dropdown_menu.js")))
+        }
+    }
+}
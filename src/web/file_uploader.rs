@@ -9,6 +9,8 @@ pub struct FileUploader<'a> {
     multiple: bool,
     disabled: bool,
     required: bool,
+    aria_describedby: Option<&'a str>,
+    invalid: bool,
 }
 
 impl<'a> FileUploader<'a> {
@@ -51,13 +53,24 @@ impl<'a> FileUploader<'a> {
         self
     }
 
+    pub fn aria_describedby(mut self, aria_describedby: &'a str) -> Self {
+        self.aria_describedby = Some(aria_describedby);
+        self
+    }
+
+    /// Render the control in its error state (`aria-invalid` and destructive colors).
+    pub fn invalid(mut self, invalid: bool) -> Self {
+        self.invalid = invalid;
+        self
+    }
+
     pub fn build(self) -> Markup {
         file_upload(self)
     }
 }
 
 fn file_upload(props: FileUploader) -> Markup {
-    let base_classes = "cursor-pointer block w-full text-sm text-foreground file:mr-4 file:py-2 file:px-4 file:rounded-md file:border-0 file:text-sm file:font-semibold file:bg-primary file:text-primary-foreground hover:file:bg-primary/90 focus-visible:outline-none focus-visible:ring-2 focus-visible:ring-ring focus-visible:ring-offset-2 disabled:cursor-not-allowed disabled:opacity-50 dark:file:bg-primary dark:file:text-primary-foreground dark:hover:file:bg-primary/90";
+    let base_classes = "cursor-pointer block w-full text-sm text-foreground file:mr-4 file:py-2 file:px-4 file:rounded-md file:border-0 file:text-sm file:font-semibold file:bg-primary file:text-primary-foreground hover:file:bg-primary/90 focus-visible:outline-none focus-visible:ring-2 focus-visible:ring-ring focus-visible:ring-offset-2 disabled:cursor-not-allowed disabled:opacity-50 dark:file:bg-primary dark:file:text-primary-foreground dark:hover:file:bg-primary/90 aria-[invalid=true]:text-destructive";
 
     let mut classes = base_classes.to_string();
     if let Some(additional_class) = props.class {
@@ -73,6 +86,8 @@ fn file_upload(props: FileUploader) -> Markup {
             accept=[props.accept]
             multiple[props.multiple]
             disabled[props.disabled]
+            aria-describedby=[props.aria_describedby]
+            aria-invalid=[props.invalid.then_some("true")]
             required[props.required] {}
     }
 }
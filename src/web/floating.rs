@@ -0,0 +1,76 @@
+use maud::{html, Markup, PreEscaped};
+
+/// Side of the anchor the floating content is placed on.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Side {
+    Top,
+    Right,
+    #[default]
+    Bottom,
+    Left,
+}
+
+impl Side {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Side::Top => "top",
+            Side::Right => "right",
+            Side::Bottom => "bottom",
+            Side::Left => "left",
+        }
+    }
+}
+
+/// Alignment of the floating content along the side of its anchor.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Align {
+    Start,
+    #[default]
+    Center,
+    End,
+}
+
+impl Align {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Align::Start => "start",
+            Align::Center => "center",
+            Align::End => "end",
+        }
+    }
+}
+
+/// Where floating content (popovers, menus, tooltips…) goes relative to its
+/// anchor, rendered as `data-floating-*` attributes read by
+/// [`ui_floating_script`].
+///
+/// The content is flipped to the opposite side when it doesn't fit in the
+/// viewport and shifted to stay inside it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Placement {
+    pub(crate) side: Side,
+    pub(crate) align: Align,
+    /// Distance from the anchor in pixels.
+    pub(crate) side_offset: u32,
+}
+
+impl Placement {
+    pub(crate) fn new(side: Side, align: Align, side_offset: u32) -> Self {
+        Self {
+            side,
+            align,
+            side_offset,
+        }
+    }
+}
+
+/// Positions the `[data-floating]` elements next to their anchor (the element
+/// right before them, or the one whose id is in `data-floating-anchor`)
+/// whenever they are shown.
+pub fn ui_floating_script() -> Markup {
+    html! {
+        script {
+            (PreEscaped(include_str!("../../scripts/floating.js")))
+        }
+    }
+}
@@ -0,0 +1,270 @@
+use std::collections::HashMap;
+
+use axum::{
+    body::Bytes,
+    extract::{multipart::MultipartError, Multipart},
+};
+use maud::{html, Markup};
+
+use super::{form_field::FormField, with_class};
+
+/// Validation errors keyed by field name.
+#[derive(Debug, Default, Clone)]
+pub struct FormErrors {
+    errors: HashMap<String, String>,
+}
+
+impl FormErrors {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record `message` for `field`, the first error of a field wins.
+    pub fn add(&mut self, field: &str, message: impl Into<String>) {
+        self.errors
+            .entry(field.to_string())
+            .or_insert_with(|| message.into());
+    }
+
+    pub fn get(&self, field: &str) -> Option<&str> {
+        self.errors.get(field).map(String::as_str)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// [`FormField`] for the control named `name`, in its error state when
+    /// `name` has an error.
+    pub fn field<'a>(&'a self, name: &'a str) -> FormField<'a> {
+        match self.get(name) {
+            Some(error) => FormField::new(name).error(error),
+            None => FormField::new(name),
+        }
+    }
+}
+
+/// Values of a form that can be checked on the server.
+pub trait Validate {
+    fn validate(&self) -> FormErrors;
+}
+
+/// File submitted in a multipart form.
+#[derive(Debug, Clone)]
+pub struct UploadedFile {
+    pub file_name: String,
+    pub content_type: Option<String>,
+    pub bytes: Bytes,
+}
+
+/// Text fields and files of a submitted multipart form.
+#[derive(Debug, Default)]
+pub struct FormData {
+    texts: HashMap<String, String>,
+    files: HashMap<String, Vec<UploadedFile>>,
+}
+
+impl FormData {
+    /// Read all the fields of `multipart`. File inputs left empty by the user
+    /// are skipped.
+    pub async fn from_multipart(mut multipart: Multipart) -> Result<Self, MultipartError> {
+        let mut data = Self::default();
+        while let Some(field) = multipart.next_field().await? {
+            let Some(name) = field.name().map(str::to_string) else {
+                continue;
+            };
+
+            match field.file_name().map(str::to_string) {
+                Some(file_name) => {
+                    let content_type = field.content_type().map(str::to_string);
+                    let bytes = field.bytes().await?;
+                    if file_name.is_empty() && bytes.is_empty() {
+                        continue;
+                    }
+                    data.files.entry(name).or_default().push(UploadedFile {
+                        file_name,
+                        content_type,
+                        bytes,
+                    });
+                }
+                None => {
+                    data.texts.insert(name, field.text().await?);
+                }
+            }
+        }
+        Ok(data)
+    }
+
+    pub fn text(&self, name: &str) -> Option<&str> {
+        self.texts.get(name).map(String::as_str)
+    }
+
+    /// Take the files submitted for `name` out of the form data.
+    pub fn take_files(&mut self, name: &str) -> Vec<UploadedFile> {
+        self.files.remove(name).unwrap_or_default()
+    }
+
+    /// Take the first file submitted for `name` out of the form data.
+    pub fn take_file(&mut self, name: &str) -> Option<UploadedFile> {
+        self.take_files(name).into_iter().next()
+    }
+}
+
+/// `<form>` submitted by htmx that replaces itself with the response, so the
+/// handler can answer with the same form re-rendered with its errors.
+pub struct Form<'a> {
+    id: &'a str,
+    hx_post: Option<&'a str>,
+    hx_put: Option<&'a str>,
+    multipart: bool,
+    class: Option<&'a str>,
+}
+
+impl<'a> Form<'a> {
+    pub fn new(id: &'a str) -> Self {
+        Self {
+            id,
+            hx_post: None,
+            hx_put: None,
+            multipart: false,
+            class: None,
+        }
+    }
+
+    pub fn hx_post(mut self, hx_post: &'a str) -> Self {
+        self.hx_post = Some(hx_post);
+        self
+    }
+
+    pub fn hx_put(mut self, hx_put: &'a str) -> Self {
+        self.hx_put = Some(hx_put);
+        self
+    }
+
+    /// Submit as `multipart/form-data`, required for file uploads.
+    pub fn multipart(mut self, multipart: bool) -> Self {
+        self.multipart = multipart;
+        self
+    }
+
+    pub fn class(mut self, class: &'a str) -> Self {
+        self.class = Some(class);
+        self
+    }
+
+    pub fn build(self, content: Markup) -> Markup {
+        form(self, content)
+    }
+}
+
+fn form(props: Form, content: Markup) -> Markup {
+    let encoding = props.multipart.then_some("multipart/form-data");
+
+    html! {
+        form
+            id=(props.id)
+            class=(with_class("space-y-4", props.class))
+            hx-post=[props.hx_post]
+            hx-put=[props.hx_put]
+            hx-encoding=[encoding]
+            enctype=[encoding]
+            hx-target="this"
+            hx-swap="outerHTML"
+        {
+            (content)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::Body,
+        extract::{FromRequest, Multipart},
+        http::{header::CONTENT_TYPE, Request},
+    };
+
+    use super::{FormData, FormErrors};
+    use crate::web::input::Input;
+
+    async fn form_data(parts: &[(&str, Option<&str>, &str)]) -> FormData {
+        let mut body = String::new();
+        for (name, file_name, content) in parts {
+            body.push_str("--BOUNDARY\r\n");
+            match file_name {
+                Some(file_name) => body.push_str(&format!(
+                    "Content-Disposition: form-data; name=\"{}\"; filename=\"{}\"\r\nContent-Type: text/plain\r\n\r\n",
+                    name, file_name
+                )),
+                None => body.push_str(&format!(
+                    "Content-Disposition: form-data; name=\"{}\"\r\n\r\n",
+                    name
+                )),
+            }
+            body.push_str(content);
+            body.push_str("\r\n");
+        }
+        body.push_str("--BOUNDARY--\r\n");
+
+        let request = Request::builder()
+            .header(CONTENT_TYPE, "multipart/form-data; boundary=BOUNDARY")
+            .body(Body::from(body))
+            .unwrap();
+        let multipart = Multipart::from_request(request, &()).await.unwrap();
+        FormData::from_multipart(multipart).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn reads_texts_and_files() {
+        let mut data = form_data(&[
+            ("title", None, "Report"),
+            ("file", Some("a.txt"), "first"),
+            ("file", Some("b.txt"), "second"),
+        ])
+        .await;
+
+        assert_eq!(data.text("title"), Some("Report"));
+        let files = data.take_files("file");
+        assert_eq!(files.len(), 2);
+        assert_eq!(files[0].file_name, "a.txt");
+        assert_eq!(files[0].content_type.as_deref(), Some("text/plain"));
+        assert_eq!(&files[1].bytes[..], b"second");
+        // Taken files are gone.
+        assert!(data.take_file("file").is_none());
+    }
+
+    #[tokio::test]
+    async fn missing_fields_and_empty_file_inputs() {
+        let mut data = form_data(&[("title", None, ""), ("file", Some(""), "")]).await;
+
+        assert_eq!(data.text("title"), Some(""));
+        assert_eq!(data.text("description"), None);
+        assert!(data.take_file("file").is_none());
+    }
+
+    #[test]
+    fn first_error_of_a_field_wins() {
+        let mut errors = FormErrors::new();
+        assert!(errors.is_empty());
+
+        errors.add("title", "Title is required.");
+        errors.add("title", "Title is too long.");
+        assert!(!errors.is_empty());
+        assert_eq!(errors.get("title"), Some("Title is required."));
+        assert_eq!(errors.get("file"), None);
+    }
+
+    #[test]
+    fn field_shows_the_error() {
+        let mut errors = FormErrors::new();
+        errors.add("title", "Title is required.");
+
+        let invalid = errors.field("title").build(Input::new()).into_string();
+        assert!(invalid.contains("Title is required."));
+        assert!(invalid.contains(r#"aria-invalid="true""#));
+        assert!(invalid.contains(r#"aria-describedby="title-error""#));
+
+        let valid = errors.field("file").build(Input::new()).into_string();
+        assert!(!valid.contains("aria-invalid"));
+    }
+}
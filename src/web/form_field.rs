@@ -0,0 +1,167 @@
+use maud::{html, Markup};
+
+use super::{
+    checkbox::Checkbox, combobox::Combobox, file_uploader::FileUploader, input::Input,
+    label::Label, radio_group::RadioGroup, select::Select, switch::Switch, textarea::Textarea,
+    with_class,
+};
+
+/// Control that can be placed in a [`FormField`], which takes care of its
+/// `id`, `aria-describedby` and `aria-invalid` attributes.
+pub trait FormControl {
+    /// `aria_labelledby` is the id of the field label, for controls that a
+    /// `<label for>` can't name.
+    fn build_field(
+        self,
+        id: &str,
+        aria_labelledby: Option<&str>,
+        aria_describedby: Option<&str>,
+        invalid: bool,
+    ) -> Markup;
+}
+
+/// Label, control, help text and error message wired together.
+///
+/// The label, description and error get the ids `{id}-label`,
+/// `{id}-description` and `{id}-error`, the last two are referenced from the
+/// control through `aria-describedby`.
+pub struct FormField<'a> {
+    id: &'a str,
+    label: Option<&'a str>,
+    description: Option<&'a str>,
+    error: Option<&'a str>,
+    class: Option<&'a str>,
+}
+
+impl<'a> FormField<'a> {
+    /// `id` is given to the control and used as prefix for the other ids.
+    pub fn new(id: &'a str) -> Self {
+        Self {
+            id,
+            label: None,
+            description: None,
+            error: None,
+            class: None,
+        }
+    }
+
+    pub fn label(mut self, label: &'a str) -> Self {
+        self.label = Some(label);
+        self
+    }
+
+    pub fn description(mut self, description: &'a str) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    /// Validation message, marks the control as invalid.
+    pub fn error(mut self, error: &'a str) -> Self {
+        self.error = Some(error);
+        self
+    }
+
+    pub fn class(mut self, class: &'a str) -> Self {
+        self.class = Some(class);
+        self
+    }
+
+    pub fn build(self, control: impl FormControl) -> Markup {
+        form_field(self, control)
+    }
+}
+
+fn form_field(props: FormField, control: impl FormControl) -> Markup {
+    let label_id = format!("{}-label", props.id);
+    let description_id = format!("{}-description", props.id);
+    let error_id = format!("{}-error", props.id);
+
+    let describedby = [
+        props.description.map(|_| description_id.as_str()),
+        props.error.map(|_| error_id.as_str()),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>()
+    .join(" ");
+
+    let invalid = props.error.is_some();
+
+    html! {
+        div class=(with_class("space-y-2", props.class)) {
+            @if let Some(label) = props.label {
+                @let label_builder = Label::new().for_id(props.id).id(&label_id);
+                @let label_builder = if invalid { label_builder.class("text-destructive") } else { label_builder };
+                (label_builder.build(html! { (label) }))
+            }
+            (control.build_field(
+                props.id,
+                props.label.map(|_| label_id.as_str()),
+                (!describedby.is_empty()).then_some(describedby.as_str()),
+                invalid,
+            ))
+            @if let Some(description) = props.description {
+                p id=(description_id) class="text-sm text-muted-foreground" {
+                    (description)
+                }
+            }
+            @if let Some(error) = props.error {
+                p id=(error_id) class="text-sm font-medium text-destructive" {
+                    (error)
+                }
+            }
+        }
+    }
+}
+
+macro_rules! impl_form_control {
+    ($($control:ident),*) => {
+        $(
+            impl<'a> FormControl for $control<'a> {
+                fn build_field(
+                    self,
+                    id: &str,
+                    _aria_labelledby: Option<&str>,
+                    aria_describedby: Option<&str>,
+                    invalid: bool,
+                ) -> Markup {
+                    let control = self.id(id).invalid(invalid);
+                    match aria_describedby {
+                        Some(aria_describedby) => control.aria_describedby(aria_describedby).build(),
+                        None => control.build(),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_form_control!(
+    Input,
+    Textarea,
+    Select,
+    FileUploader,
+    Checkbox,
+    Switch,
+    Combobox
+);
+
+impl<'a> FormControl for RadioGroup<'a> {
+    fn build_field(
+        self,
+        id: &str,
+        aria_labelledby: Option<&str>,
+        aria_describedby: Option<&str>,
+        invalid: bool,
+    ) -> Markup {
+        let control = self.id(id).invalid(invalid);
+        let control = match aria_labelledby {
+            Some(aria_labelledby) => control.aria_labelledby(aria_labelledby),
+            None => control,
+        };
+        match aria_describedby {
+            Some(aria_describedby) => control.aria_describedby(aria_describedby).build(),
+            None => control.build(),
+        }
+    }
+}
@@ -7,6 +7,16 @@ pub enum InputSize {
     Lg,
 }
 
+impl InputSize {
+    pub(crate) fn classes(self) -> &'static str {
+        match self {
+            InputSize::Default => "h-10",
+            InputSize::Sm => "h-9",
+            InputSize::Lg => "h-11",
+        }
+    }
+}
+
 pub struct Input<'a> {
     size: InputSize,
     placeholder: Option<&'a str>,
@@ -17,6 +27,8 @@ pub struct Input<'a> {
     class: Option<&'a str>,
     disabled: bool,
     required: bool,
+    aria_describedby: Option<&'a str>,
+    invalid: bool,
 }
 
 impl<'a> Default for Input<'a> {
@@ -31,6 +43,8 @@ impl<'a> Default for Input<'a> {
             ty: None,
             disabled: false,
             required: false,
+            aria_describedby: None,
+            invalid: false,
         }
     }
 }
@@ -85,21 +99,26 @@ impl<'a> Input<'a> {
         self
     }
 
+    pub fn aria_describedby(mut self, aria_describedby: &'a str) -> Self {
+        self.aria_describedby = Some(aria_describedby);
+        self
+    }
+
+    /// Render the control in its error state (`aria-invalid` and destructive colors).
+    pub fn invalid(mut self, invalid: bool) -> Self {
+        self.invalid = invalid;
+        self
+    }
+
     pub fn build(self) -> Markup {
         input(self)
     }
 }
 
-fn input(props: Input) -> Markup {
-    let base_classes = "flex w-full rounded-md border border-input bg-background text-foreground px-3 py-2 text-sm ring-offset-background file:border-0 file:bg-transparent file:text-sm file:font-medium placeholder:text-muted-foreground focus-visible:outline-none focus-visible:ring-2 focus-visible:ring-ring focus-visible:ring-offset-2 disabled:cursor-not-allowed disabled:opacity-50";
-
-    let size_classes = match props.size {
-        InputSize::Default => "h-10",
-        InputSize::Sm => "h-9",
-        InputSize::Lg => "h-11",
-    };
+pub(crate) const INPUT_CLASSES: &str = "flex w-full rounded-md border border-input bg-background text-foreground px-3 py-2 text-sm ring-offset-background file:border-0 file:bg-transparent file:text-sm file:font-medium placeholder:text-muted-foreground focus-visible:outline-none focus-visible:ring-2 focus-visible:ring-ring focus-visible:ring-offset-2 disabled:cursor-not-allowed disabled:opacity-50 aria-[invalid=true]:border-destructive aria-[invalid=true]:focus-visible:ring-destructive";
 
-    let mut classes = format!("{} {}", base_classes, size_classes);
+fn input(props: Input) -> Markup {
+    let mut classes = format!("{} {}", INPUT_CLASSES, props.size.classes());
     if let Some(additional_class) = props.class {
         classes.push_str(&format!(" {}", additional_class));
     }
@@ -114,6 +133,8 @@ fn input(props: Input) -> Markup {
             name=[props.name]
             spellcheck="false"
             disabled[props.disabled]
+            aria-describedby=[props.aria_describedby]
+            aria-invalid=[props.invalid.then_some("true")]
             required[props.required] {}
     }
 }
@@ -0,0 +1,47 @@
+use maud::{html, Markup};
+
+use super::with_class;
+
+#[derive(Default)]
+pub struct Label<'a> {
+    for_id: Option<&'a str>,
+    id: Option<&'a str>,
+    class: Option<&'a str>,
+}
+
+impl<'a> Label<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Id of the labelled control (the `for` attribute).
+    pub fn for_id(mut self, for_id: &'a str) -> Self {
+        self.for_id = Some(for_id);
+        self
+    }
+
+    pub fn id(mut self, id: &'a str) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    pub fn class(mut self, class: &'a str) -> Self {
+        self.class = Some(class);
+        self
+    }
+
+    pub fn build(self, content: Markup) -> Markup {
+        label(self, content)
+    }
+}
+
+fn label(props: Label, content: Markup) -> Markup {
+    let base_classes = "text-sm font-medium leading-none peer-disabled:cursor-not-allowed peer-disabled:opacity-70";
+    let classes = with_class(base_classes, props.class);
+
+    html! {
+        label class=(classes) for=[props.for_id] id=[props.id] {
+            (content)
+        }
+    }
+}
@@ -0,0 +1,76 @@
+/// Node of a navigation tree, e.g. the site map, rendered by
+/// [`NavigationMenu`](super::navigation_menu::NavigationMenu) and
+/// [`Menubar`](super::menubar::Menubar).
+///
+/// ```ignore
+/// let site = vec![
+///     MenuEntry::link("Home", "/"),
+///     MenuEntry::menu("Docs", vec![
+///         MenuEntry::link("Installation", "/docs/installation")
+///             .description("How to install dependencies and structure your app."),
+///         MenuEntry::link("Components", "/docs/components"),
+///     ]),
+/// ];
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum MenuEntry<'a> {
+    Link {
+        label: &'a str,
+        href: &'a str,
+        description: Option<&'a str>,
+    },
+    Menu {
+        label: &'a str,
+        entries: Vec<MenuEntry<'a>>,
+    },
+    Separator,
+}
+
+impl<'a> MenuEntry<'a> {
+    pub fn link(label: &'a str, href: &'a str) -> Self {
+        MenuEntry::Link {
+            label,
+            href,
+            description: None,
+        }
+    }
+
+    pub fn menu(label: &'a str, entries: Vec<MenuEntry<'a>>) -> Self {
+        MenuEntry::Menu { label, entries }
+    }
+
+    /// Set the description of a link, other entries are left as is.
+    pub fn description(self, description: &'a str) -> Self {
+        match self {
+            MenuEntry::Link { label, href, .. } => MenuEntry::Link {
+                label,
+                href,
+                description: Some(description),
+            },
+            entry => entry,
+        }
+    }
+
+    /// Whether the page at `path` is this link, or below it.
+    pub(crate) fn is_active(&self, path: &str) -> bool {
+        match self {
+            MenuEntry::Link { href, .. } => {
+                let href = href.trim_end_matches('/');
+                self.is_current(path)
+                    || (!href.is_empty() && path.starts_with(&format!("{}/", href)))
+            }
+            MenuEntry::Menu { entries, .. } => entries.iter().any(|entry| entry.is_active(path)),
+            MenuEntry::Separator => false,
+        }
+    }
+
+    /// Whether the page at `path` is exactly this link.
+    pub(crate) fn is_current(&self, path: &str) -> bool {
+        match self {
+            MenuEntry::Link { href, .. } => {
+                path.trim_end_matches('/') == href.trim_end_matches('/')
+            }
+            _ => false,
+        }
+    }
+}
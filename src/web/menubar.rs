@@ -0,0 +1,119 @@
+use maud::{html, Markup, PreEscaped};
+
+use super::{
+    dropdown_menu::{menu, DropdownMenuContent},
+    floating::{Align, Placement, Side},
+    menu::MenuEntry,
+    with_class,
+};
+
+const TRIGGER_CLASSES: &str = "flex cursor-default select-none items-center rounded-sm px-3 py-1.5 text-sm font-medium outline-none focus:bg-accent focus:text-accent-foreground aria-expanded:bg-accent aria-expanded:text-accent-foreground";
+
+enum MenubarEntry<'a> {
+    Menu(&'a str, DropdownMenuContent<'a>),
+    Link(&'a str, &'a str),
+}
+
+/// Row of menus, as in desktop applications.
+///
+/// ArrowLeft/ArrowRight move between the menus, and switch to the next menu
+/// when one is open. Requires [`ui_menubar_script`],
+/// [`ui_dropdown_menu_script`](super::dropdown_menu::ui_dropdown_menu_script)
+/// and [`ui_floating_script`](super::floating::ui_floating_script) on the page.
+pub struct Menubar<'a> {
+    id: &'a str,
+    entries: Vec<MenubarEntry<'a>>,
+    class: Option<&'a str>,
+}
+
+impl<'a> Menubar<'a> {
+    pub fn new(id: &'a str) -> Self {
+        Self {
+            id,
+            entries: Vec::new(),
+            class: None,
+        }
+    }
+
+    /// Menubar with a menu per top-level menu of `entries`, and a plain link
+    /// per top-level link. Top-level separators are ignored.
+    pub fn from_entries(id: &'a str, entries: &[MenuEntry<'a>]) -> Self {
+        entries
+            .iter()
+            .fold(Self::new(id), |menubar, entry| match entry {
+                MenuEntry::Link { label, href, .. } => menubar.link(label, href),
+                MenuEntry::Menu { label, entries } => {
+                    menubar.menu(label, DropdownMenuContent::from_entries(entries))
+                }
+                MenuEntry::Separator => menubar,
+            })
+    }
+
+    pub fn menu(mut self, label: &'a str, content: DropdownMenuContent<'a>) -> Self {
+        self.entries.push(MenubarEntry::Menu(label, content));
+        self
+    }
+
+    pub fn link(mut self, label: &'a str, href: &'a str) -> Self {
+        self.entries.push(MenubarEntry::Link(label, href));
+        self
+    }
+
+    pub fn class(mut self, class: &'a str) -> Self {
+        self.class = Some(class);
+        self
+    }
+
+    pub fn build(self) -> Markup {
+        menubar(self)
+    }
+}
+
+fn menubar(props: Menubar) -> Markup {
+    let base_classes =
+        "flex h-10 items-center space-x-1 rounded-md border border-border bg-background p-1";
+    let placement = Placement::new(Side::Bottom, Align::Start, 8);
+
+    html! {
+        div class=(with_class(base_classes, props.class)) id=(props.id) role="menubar" data-menubar {
+            @for (index, entry) in props.entries.iter().enumerate() {
+                // Only the first entry is in the tab sequence (roving tabindex).
+                @let tabindex = if index == 0 { "0" } else { "-1" };
+                @match entry {
+                    MenubarEntry::Menu(label, content) => {
+                        @let trigger_id = format!("{}-{}-trigger", props.id, index);
+                        @let content_id = format!("{}-{}-content", props.id, index);
+                        div role="none" data-dropdown-menu {
+                            button
+                                class=(TRIGGER_CLASSES)
+                                type="button"
+                                role="menuitem"
+                                id=(trigger_id)
+                                tabindex=(tabindex)
+                                aria-haspopup="menu"
+                                aria-expanded="false"
+                                aria-controls=(content_id)
+                            {
+                                (label)
+                            }
+                            (menu(content, &content_id, &trigger_id, placement))
+                        }
+                    },
+                    MenubarEntry::Link(label, href) => {
+                        a class=(TRIGGER_CLASSES) role="menuitem" href=(href) tabindex=(tabindex) {
+                            (label)
+                        }
+                    },
+                }
+            }
+        }
+    }
+}
+
+pub fn ui_menubar_script() -> Markup {
+    html! {
+        script {
+            (PreEscaped(include_str!("../../scripts/menubar.js")))
+        }
+    }
+}
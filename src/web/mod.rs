@@ -1,10 +1,88 @@
+mod accordion;
+mod alert;
+mod alert_dialog;
+mod avatar;
+mod badge;
+mod breadcrumb;
 mod button;
+mod card;
+mod checkbox;
+mod collapsible;
+mod combobox;
+mod command;
+mod data_table;
+mod dialog;
+mod drawer;
+mod dropdown_menu;
 mod file_uploader;
+mod floating;
+mod form;
+mod form_field;
 mod input;
+mod label;
+mod menu;
+mod menubar;
+mod navigation_menu;
+mod pagination;
+mod popover;
+mod radio_group;
+mod select;
+mod sheet;
+mod switch;
+mod table;
+mod tabs;
+mod textarea;
+mod toast;
 mod toggle_theme;
+mod tooltip;
 
 pub mod prelude {
     pub use super::{
-        button::*, file_uploader::FileUploader, input::*, toggle_theme::ui_theme_toggle,
+        accordion::*,
+        alert::*,
+        alert_dialog::AlertDialog,
+        avatar::*,
+        badge::*,
+        breadcrumb::*,
+        button::*,
+        card::*,
+        checkbox::Checkbox,
+        collapsible::Collapsible,
+        combobox::*,
+        command::*,
+        data_table::*,
+        dialog::Dialog,
+        drawer::{ui_drawer_script, Drawer},
+        dropdown_menu::*,
+        file_uploader::FileUploader,
+        floating::{ui_floating_script, Align, Side},
+        form::*,
+        form_field::*,
+        input::*,
+        label::Label,
+        menu::MenuEntry,
+        menubar::*,
+        navigation_menu::*,
+        pagination::Pagination,
+        popover::Popover,
+        radio_group::*,
+        select::*,
+        sheet::*,
+        switch::Switch,
+        table::*,
+        tabs::*,
+        textarea::Textarea,
+        toast::*,
+        toggle_theme::ui_theme_toggle,
+        tooltip::*,
     };
 }
+
+/// Append the user supplied `class` (if any) to the component `base_classes`.
+pub(crate) fn with_class(base_classes: &str, class: Option<&str>) -> String {
+    let mut classes = base_classes.to_string();
+    if let Some(additional_class) = class {
+        classes.push_str(&format!(" {}", additional_class));
+    }
+    classes
+}
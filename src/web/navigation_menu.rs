@@ -0,0 +1,170 @@
+use maud::{html, Markup, PreEscaped};
+
+use super::{
+    floating::{Align, Placement, Side},
+    menu::MenuEntry,
+    with_class,
+};
+
+const TRIGGER_CLASSES: &str = "group inline-flex h-10 w-max items-center justify-center rounded-md bg-background px-4 py-2 text-sm font-medium transition-colors hover:bg-accent hover:text-accent-foreground focus:bg-accent focus:text-accent-foreground focus:outline-none disabled:pointer-events-none disabled:opacity-50 data-[active]:bg-accent/50 aria-expanded:bg-accent/50";
+
+/// Site navigation bar whose nested menus open as panels of links.
+///
+/// The link of the current page (given with `current_path`, e.g. from the
+/// request `Uri`) gets `aria-current="page"`, and it and the menu containing
+/// it are highlighted. Requires [`ui_navigation_menu_script`] and
+/// [`ui_floating_script`](super::floating::ui_floating_script) on the page.
+pub struct NavigationMenu<'a> {
+    id: &'a str,
+    entries: &'a [MenuEntry<'a>],
+    current_path: Option<&'a str>,
+    aria_label: &'a str,
+    class: Option<&'a str>,
+}
+
+impl<'a> NavigationMenu<'a> {
+    pub fn new(id: &'a str, entries: &'a [MenuEntry<'a>]) -> Self {
+        Self {
+            id,
+            entries,
+            current_path: None,
+            aria_label: "Main",
+            class: None,
+        }
+    }
+
+    /// Path of the current page, e.g. `uri.path()`.
+    pub fn current_path(mut self, current_path: &'a str) -> Self {
+        self.current_path = Some(current_path);
+        self
+    }
+
+    pub fn aria_label(mut self, aria_label: &'a str) -> Self {
+        self.aria_label = aria_label;
+        self
+    }
+
+    pub fn class(mut self, class: &'a str) -> Self {
+        self.class = Some(class);
+        self
+    }
+
+    pub fn build(self) -> Markup {
+        navigation_menu(self)
+    }
+}
+
+fn navigation_menu(props: NavigationMenu) -> Markup {
+    let base_classes = "relative z-10 flex max-w-max flex-1 items-center justify-center";
+    let panel_classes =
+        "z-50 rounded-md border border-border bg-popover text-popover-foreground shadow-lg";
+    let placement = Placement::new(Side::Bottom, Align::Start, 6);
+    let path = props.current_path.unwrap_or_default();
+    let active = |entry: &MenuEntry| props.current_path.is_some() && entry.is_active(path);
+
+    html! {
+        nav
+            class=(with_class(base_classes, props.class))
+            id=(props.id)
+            aria-label=(props.aria_label)
+            data-navigation-menu
+        {
+            ul class="flex flex-1 list-none items-center justify-center space-x-1" {
+                @for (index, entry) in props.entries.iter().enumerate() {
+                    @match entry {
+                        MenuEntry::Link { label, href, .. } => {
+                            li {
+                                a
+                                    class=(TRIGGER_CLASSES)
+                                    href=(href)
+                                    aria-current=[(props.current_path.is_some() && entry.is_current(path)).then_some("page")]
+                                    data-active[active(entry)]
+                                {
+                                    (label)
+                                }
+                            }
+                        },
+                        MenuEntry::Menu { label, entries } => {
+                            @let panel_id = format!("{}-panel-{}", props.id, index);
+                            li {
+                                button
+                                    class=(TRIGGER_CLASSES)
+                                    type="button"
+                                    aria-expanded="false"
+                                    aria-controls=(panel_id)
+                                    data-active[active(entry)]
+                                {
+                                    (label)
+                                    svg class="relative top-px ml-1 h-3 w-3 transition duration-200 group-aria-expanded:rotate-180" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round" viewBox="0 0 24 24" xmlns="http://www.w3.org/2000/svg" aria-hidden="true" {
+                                        path d="m6 9 6 6 6-6" {}
+                                    }
+                                }
+                                div
+                                    class=(panel_classes)
+                                    id=(panel_id)
+                                    hidden
+                                    data-floating
+                                    data-floating-side=(placement.side.as_str())
+                                    data-floating-align=(placement.align.as_str())
+                                    data-floating-offset=(placement.side_offset)
+                                {
+                                    ul class="grid w-[400px] gap-3 p-4 md:w-[500px] md:grid-cols-2" {
+                                        (panel_entries(entries, props.current_path))
+                                    }
+                                }
+                            }
+                        },
+                        MenuEntry::Separator => {},
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn panel_entries(entries: &[MenuEntry], current_path: Option<&str>) -> Markup {
+    let link_classes = "block select-none space-y-1 rounded-md p-3 leading-none no-underline outline-none transition-colors hover:bg-accent hover:text-accent-foreground focus:bg-accent focus:text-accent-foreground data-[active]:bg-accent/50";
+    let path = current_path.unwrap_or_default();
+
+    html! {
+        @for entry in entries {
+            @match entry {
+                MenuEntry::Link { label, href, description } => {
+                    li {
+                        a
+                            class=(link_classes)
+                            href=(href)
+                            aria-current=[(current_path.is_some() && entry.is_current(path)).then_some("page")]
+                            data-active[current_path.is_some() && entry.is_active(path)]
+                        {
+                            div class="text-sm font-medium leading-none" { (label) }
+                            @if let Some(description) = description {
+                                p class="line-clamp-2 text-sm leading-snug text-muted-foreground" {
+                                    (description)
+                                }
+                            }
+                        }
+                    }
+                },
+                // Nested menus are shown as a titled column of links.
+                MenuEntry::Menu { label, entries } => {
+                    li class="space-y-1" {
+                        p class="px-3 pt-1 text-xs font-medium text-muted-foreground" { (label) }
+                        ul class="space-y-1" {
+                            (panel_entries(entries, current_path))
+                        }
+                    }
+                },
+                MenuEntry::Separator => li class="col-span-full h-px bg-border" role="separator" {},
+            }
+        }
+    }
+}
+
+pub fn ui_navigation_menu_script() -> Markup {
+    html! {
+        script {
+            (PreEscaped(include_str!("../../scripts/navigation_menu.js")))
+        }
+    }
+}
@@ -0,0 +1,270 @@
+use maud::{html, Markup};
+
+use super::{
+    button::{button_classes, ButtonSize, ButtonVariant},
+    with_class,
+};
+
+/// Page links with previous/next controls and ellipses for long ranges.
+///
+/// Only the first and last pages and `siblings` pages around the current one
+/// are shown. Hrefs are generated by `href` (or `url_template`); with
+/// `hx_get` the links also load the page through htmx.
+pub struct Pagination<'a> {
+    current: usize,
+    total_pages: usize,
+    siblings: usize,
+    href: Box<dyn Fn(usize) -> String + 'a>,
+    hx_get: bool,
+    push_url: bool,
+    hx_target: Option<&'a str>,
+    hx_swap: Option<&'a str>,
+    class: Option<&'a str>,
+}
+
+impl<'a> Pagination<'a> {
+    /// `current` is 1-based.
+    pub fn new(current: usize, total_pages: usize) -> Self {
+        let total_pages = total_pages.max(1);
+        Self {
+            current: current.clamp(1, total_pages),
+            total_pages,
+            siblings: 1,
+            href: Box::new(|page| format!("?page={}", page)),
+            hx_get: false,
+            push_url: false,
+            hx_target: None,
+            hx_swap: None,
+            class: None,
+        }
+    }
+
+    pub fn from_items(current: usize, total_items: usize, page_size: usize) -> Self {
+        Self::new(current, total_items.div_ceil(page_size.max(1)))
+    }
+
+    /// Number of pages shown on each side of the current one.
+    pub fn siblings(mut self, siblings: usize) -> Self {
+        self.siblings = siblings;
+        self
+    }
+
+    pub fn href(mut self, href: impl Fn(usize) -> String + 'a) -> Self {
+        self.href = Box::new(href);
+        self
+    }
+
+    /// Generate hrefs by replacing `{page}` in `template`, e.g. `/users?page={page}`.
+    pub fn url_template(self, template: &'a str) -> Self {
+        self.href(move |page| template.replace("{page}", &page.to_string()))
+    }
+
+    /// Load the pages through htmx. Without `hx_target` (or an `hx-target`
+    /// inherited from an ancestor) htmx swaps the page into the clicked link.
+    pub fn hx_get(mut self, hx_get: bool) -> Self {
+        self.hx_get = hx_get;
+        self
+    }
+
+    /// Push the pages loaded through htmx into the browser history, the hrefs
+    /// must then render the full page for requests without `HX-Request`.
+    pub fn push_url(mut self, push_url: bool) -> Self {
+        self.push_url = push_url;
+        self
+    }
+
+    /// Element receiving the page, implies `hx_get`.
+    pub fn hx_target(mut self, hx_target: &'a str) -> Self {
+        self.hx_get = true;
+        self.hx_target = Some(hx_target);
+        self
+    }
+
+    pub fn hx_swap(mut self, hx_swap: &'a str) -> Self {
+        self.hx_swap = Some(hx_swap);
+        self
+    }
+
+    pub fn class(mut self, class: &'a str) -> Self {
+        self.class = Some(class);
+        self
+    }
+
+    pub fn build(self) -> Markup {
+        pagination(self)
+    }
+}
+
+/// Pages to display, `None` standing for an ellipsis.
+fn page_window(current: usize, total_pages: usize, siblings: usize) -> Vec<Option<usize>> {
+    let start = current.saturating_sub(siblings).max(1);
+    let end = (current + siblings).min(total_pages);
+
+    let mut visible = vec![1];
+    visible.extend(start..=end);
+    visible.push(total_pages);
+    visible.sort_unstable();
+    visible.dedup();
+
+    let mut pages = Vec::new();
+    for page in visible {
+        match pages.last() {
+            // Showing the single hidden page takes as much room as an ellipsis.
+            Some(Some(last)) if page == last + 2 => pages.push(Some(last + 1)),
+            Some(Some(last)) if page > last + 2 => pages.push(None),
+            _ => {}
+        }
+        pages.push(Some(page));
+    }
+    pages
+}
+
+fn pagination(props: Pagination) -> Markup {
+    let link = |page: usize,
+                content: Markup,
+                variant: ButtonVariant,
+                size: ButtonSize,
+                extra: Option<&str>,
+                aria_label: &str| {
+        let href = (props.href)(page);
+        let current = page == props.current;
+        html! {
+            a
+                class=(with_class(&button_classes(&variant, &size), extra))
+                href=(href)
+                hx-get=[props.hx_get.then_some(&href)]
+                hx-target=[props.hx_target]
+                hx-swap=[props.hx_swap]
+                hx-push-url=[props.hx_get.then_some(props.push_url)]
+                aria-current=[current.then_some("page")]
+                aria-label=(aria_label)
+            {
+                (content)
+            }
+        }
+    };
+    let disabled_link = |content: Markup, extra: &str, aria_label: &str| {
+        html! {
+            a
+                class=(with_class(&button_classes(&ButtonVariant::Ghost, &ButtonSize::Default), Some(extra)))
+                role="link"
+                aria-disabled="true"
+                aria-label=(aria_label)
+            {
+                (content)
+            }
+        }
+    };
+
+    let previous = html! {
+        svg class="h-4 w-4" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round" viewBox="0 0 24 24" xmlns="http://www.w3.org/2000/svg" aria-hidden="true" {
+            path d="m15 18-6-6 6-6" {}
+        }
+        span { "Previous" }
+    };
+    let next = html! {
+        span { "Next" }
+        svg class="h-4 w-4" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round" viewBox="0 0 24 24" xmlns="http://www.w3.org/2000/svg" aria-hidden="true" {
+            path d="m9 18 6-6-6-6" {}
+        }
+    };
+
+    html! {
+        nav
+            class=(with_class("mx-auto flex w-full justify-center", props.class))
+            role="navigation"
+            aria-label="pagination"
+        {
+            ul class="flex flex-row items-center gap-1" {
+                li {
+                    @if props.current > 1 {
+                        (link(props.current - 1, previous, ButtonVariant::Ghost, ButtonSize::Default, Some("gap-1 pl-2.5"), "Go to previous page"))
+                    } @else {
+                        (disabled_link(previous, "gap-1 pl-2.5 pointer-events-none opacity-50", "Go to previous page"))
+                    }
+                }
+                @for page in page_window(props.current, props.total_pages, props.siblings) {
+                    li {
+                        @match page {
+                            Some(page) => {
+                                @let variant = if page == props.current { ButtonVariant::Outline } else { ButtonVariant::Ghost };
+                                (link(page, html! { (page) }, variant, ButtonSize::Icon, None, &format!("Go to page {}", page)))
+                            },
+                            None => {
+                                span class="flex h-9 w-9 items-center justify-center" aria-hidden="true" {
+                                    "…"
+                                }
+                                span class="sr-only" { "More pages" }
+                            },
+                        }
+                    }
+                }
+                li {
+                    @if props.current < props.total_pages {
+                        (link(props.current + 1, next, ButtonVariant::Ghost, ButtonSize::Default, Some("gap-1 pr-2.5"), "Go to next page"))
+                    } @else {
+                        (disabled_link(next, "gap-1 pr-2.5 pointer-events-none opacity-50", "Go to next page"))
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::page_window;
+
+    #[test]
+    fn single_and_two_pages() {
+        assert_eq!(page_window(1, 1, 1), vec![Some(1)]);
+        assert_eq!(page_window(1, 2, 1), vec![Some(1), Some(2)]);
+        assert_eq!(page_window(2, 2, 1), vec![Some(1), Some(2)]);
+    }
+
+    #[test]
+    fn first_and_last_pages() {
+        assert_eq!(
+            page_window(1, 10, 1),
+            vec![Some(1), Some(2), None, Some(10)]
+        );
+        assert_eq!(
+            page_window(10, 10, 1),
+            vec![Some(1), None, Some(9), Some(10)]
+        );
+    }
+
+    #[test]
+    fn middle_page() {
+        assert_eq!(
+            page_window(5, 10, 1),
+            vec![Some(1), None, Some(4), Some(5), Some(6), None, Some(10)]
+        );
+        assert_eq!(
+            page_window(6, 12, 2),
+            vec![
+                Some(1),
+                None,
+                Some(4),
+                Some(5),
+                Some(6),
+                Some(7),
+                Some(8),
+                None,
+                Some(12)
+            ]
+        );
+    }
+
+    #[test]
+    fn single_hidden_page_is_shown() {
+        assert_eq!(
+            page_window(4, 10, 1),
+            vec![Some(1), Some(2), Some(3), Some(4), Some(5), None, Some(10)]
+        );
+        assert_eq!(
+            page_window(7, 10, 1),
+            vec![Some(1), None, Some(6), Some(7), Some(8), Some(9), Some(10)]
+        );
+    }
+}
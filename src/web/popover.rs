@@ -0,0 +1,114 @@
+use maud::{html, Markup};
+
+use super::{
+    button::Button,
+    floating::{Align, Placement, Side},
+    with_class,
+};
+
+/// Floating panel opened by a [`Button`], built on the popover API so the
+/// browser takes care of closing it on Escape or on a click outside.
+///
+/// When `hx_get` is set, the content is (re)loaded from the server every time
+/// the popover is opened and the `build` content is shown while loading.
+/// Requires [`ui_floating_script`](super::floating::ui_floating_script) on the page.
+pub struct Popover<'a> {
+    id: &'a str,
+    trigger: Option<(Button<'a>, Markup)>,
+    placement: Placement,
+    hx_get: Option<&'a str>,
+    class: Option<&'a str>,
+}
+
+impl<'a> Popover<'a> {
+    pub fn new(id: &'a str) -> Self {
+        Self {
+            id,
+            trigger: None,
+            placement: Placement::new(Side::Bottom, Align::Center, 4),
+            hx_get: None,
+            class: None,
+        }
+    }
+
+    /// Button toggling the popover. Without it, any element with id
+    /// `{id}-trigger` and `popovertarget="{id}"` can be used.
+    pub fn trigger(mut self, button: Button<'a>, content: Markup) -> Self {
+        self.trigger = Some((button, content));
+        self
+    }
+
+    pub fn side(mut self, side: Side) -> Self {
+        self.placement.side = side;
+        self
+    }
+
+    pub fn align(mut self, align: Align) -> Self {
+        self.placement.align = align;
+        self
+    }
+
+    /// Distance from the trigger in pixels.
+    pub fn side_offset(mut self, side_offset: u32) -> Self {
+        self.placement.side_offset = side_offset;
+        self
+    }
+
+    pub fn hx_get(mut self, hx_get: &'a str) -> Self {
+        self.hx_get = Some(hx_get);
+        self
+    }
+
+    pub fn class(mut self, class: &'a str) -> Self {
+        self.class = Some(class);
+        self
+    }
+
+    pub fn build(self, content: Markup) -> Markup {
+        popover(self, content)
+    }
+}
+
+fn popover(props: Popover, content: Markup) -> Markup {
+    let base_classes = "z-50 w-72 rounded-md border border-border bg-popover p-4 text-popover-foreground shadow-md outline-none";
+
+    let trigger_id = format!("{}-trigger", props.id);
+    let body_id = format!("{}-body", props.id);
+    let has_trigger = props.trigger.is_some();
+    let hyperscript = format!(
+        "on toggle set @aria-expanded of #{trigger_id} to (event.newState is 'open') \
+         then if event.newState is 'open' send popover:open to #{body_id} end"
+    );
+
+    html! {
+        @if let Some((button, trigger_content)) = props.trigger {
+            (button
+                .ty("button")
+                .id(&trigger_id)
+                .popup("dialog", props.id)
+                .popover_target(props.id)
+                .build(trigger_content))
+        }
+        div
+            class=(with_class(base_classes, props.class))
+            id=(props.id)
+            role="dialog"
+            aria-labelledby=[has_trigger.then_some(&trigger_id)]
+            popover
+            data-floating
+            data-floating-side=(props.placement.side.as_str())
+            data-floating-align=(props.placement.align.as_str())
+            data-floating-offset=(props.placement.side_offset)
+            data-floating-anchor=(trigger_id)
+            _=(hyperscript)
+        {
+            div
+                id=(body_id)
+                hx-get=[props.hx_get]
+                hx-trigger=[props.hx_get.map(|_| "popover:open")]
+            {
+                (content)
+            }
+        }
+    }
+}
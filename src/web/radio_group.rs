@@ -0,0 +1,166 @@
+use maud::{html, Markup};
+
+use super::{label::Label, with_class};
+
+pub struct RadioGroupItem<'a> {
+    value: &'a str,
+    id: Option<&'a str>,
+    label: Option<&'a str>,
+    disabled: bool,
+}
+
+impl<'a> RadioGroupItem<'a> {
+    pub fn new(value: &'a str) -> Self {
+        Self {
+            value,
+            id: None,
+            label: None,
+            disabled: false,
+        }
+    }
+
+    /// Defaults to `{name}-{value}`, `name` being the one of the group.
+    pub fn id(mut self, id: &'a str) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    pub fn label(mut self, label: &'a str) -> Self {
+        self.label = Some(label);
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+/// Set of native radio inputs sharing `name`, the browser provides the
+/// arrow-key navigation between them.
+pub struct RadioGroup<'a> {
+    name: &'a str,
+    items: Vec<RadioGroupItem<'a>>,
+    id: Option<&'a str>,
+    value: Option<&'a str>,
+    class: Option<&'a str>,
+    disabled: bool,
+    required: bool,
+    aria_labelledby: Option<&'a str>,
+    aria_describedby: Option<&'a str>,
+    invalid: bool,
+}
+
+impl<'a> RadioGroup<'a> {
+    pub fn new(name: &'a str) -> Self {
+        Self {
+            name,
+            items: Vec::new(),
+            id: None,
+            value: None,
+            class: None,
+            disabled: false,
+            required: false,
+            aria_labelledby: None,
+            aria_describedby: None,
+            invalid: false,
+        }
+    }
+
+    pub fn item(mut self, item: RadioGroupItem<'a>) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    pub fn id(mut self, id: &'a str) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Value of the checked item.
+    pub fn value(mut self, value: &'a str) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    pub fn class(mut self, class: &'a str) -> Self {
+        self.class = Some(class);
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    pub fn required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+
+    /// Id of the element naming the group, a `<label for>` can't name it.
+    pub fn aria_labelledby(mut self, aria_labelledby: &'a str) -> Self {
+        self.aria_labelledby = Some(aria_labelledby);
+        self
+    }
+
+    pub fn aria_describedby(mut self, aria_describedby: &'a str) -> Self {
+        self.aria_describedby = Some(aria_describedby);
+        self
+    }
+
+    /// Render the control in its error state (`aria-invalid` and destructive colors).
+    pub fn invalid(mut self, invalid: bool) -> Self {
+        self.invalid = invalid;
+        self
+    }
+
+    pub fn build(self) -> Markup {
+        radio_group(self)
+    }
+}
+
+fn radio_group(props: RadioGroup) -> Markup {
+    let radio_classes = "peer aspect-square h-4 w-4 shrink-0 cursor-pointer appearance-none rounded-full border border-primary ring-offset-background focus:outline-none focus-visible:ring-2 focus-visible:ring-ring focus-visible:ring-offset-2 disabled:cursor-not-allowed disabled:opacity-50 group-aria-[invalid=true]:border-destructive";
+
+    html! {
+        div
+            class=(with_class("group grid gap-2", props.class))
+            role="radiogroup"
+            id=[props.id]
+            aria-required=[props.required.then_some("true")]
+            aria-labelledby=[props.aria_labelledby]
+            aria-describedby=[props.aria_describedby]
+            aria-invalid=[props.invalid.then_some("true")]
+        {
+            @for item in props.items {
+                @let disabled = props.disabled || item.disabled;
+                // The label needs an id to point at.
+                @let id = item.id.map(str::to_string).unwrap_or_else(|| format!("{}-{}", props.name, item.value));
+                div class="flex items-center space-x-2" {
+                    span class="relative inline-flex h-4 w-4 shrink-0" {
+                        input
+                            class=(radio_classes)
+                            type="radio"
+                            id=(id)
+                            name=(props.name)
+                            value=(item.value)
+                            checked[props.value == Some(item.value)]
+                            disabled[disabled]
+                            required[props.required] {}
+                        span class="pointer-events-none absolute left-1/2 top-1/2 hidden h-2.5 w-2.5 -translate-x-1/2 -translate-y-1/2 rounded-full bg-primary peer-checked:block" {}
+                    }
+                    @if let Some(label) = item.label {
+                        @let label_builder = Label::new().for_id(&id);
+                        @let label_builder = if disabled {
+                            label_builder.class("cursor-not-allowed opacity-70")
+                        } else {
+                            label_builder
+                        };
+                        (label_builder.build(html! { (label) }))
+                    }
+                }
+            }
+        }
+    }
+}
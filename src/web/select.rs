@@ -0,0 +1,342 @@
+use maud::{html, Markup, PreEscaped};
+
+use super::{input::InputSize, with_class};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SelectMode {
+    /// Native `<select>` element styled like [`Input`](super::input::Input).
+    Native,
+    /// Custom listbox with keyboard navigation and typeahead, requires [`ui_select_script`].
+    /// The ids of the listbox and its options are derived from the select
+    /// `id` (or `name`), one of them must be set.
+    Listbox,
+}
+
+pub struct SelectOption<'a> {
+    value: &'a str,
+    label: &'a str,
+    disabled: bool,
+}
+
+impl<'a> SelectOption<'a> {
+    pub fn new(value: &'a str, label: &'a str) -> Self {
+        Self {
+            value,
+            label,
+            disabled: false,
+        }
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+pub struct SelectGroup<'a> {
+    label: &'a str,
+    options: Vec<SelectOption<'a>>,
+}
+
+impl<'a> SelectGroup<'a> {
+    pub fn new(label: &'a str) -> Self {
+        Self {
+            label,
+            options: Vec::new(),
+        }
+    }
+
+    pub fn option(mut self, option: SelectOption<'a>) -> Self {
+        self.options.push(option);
+        self
+    }
+}
+
+enum SelectItem<'a> {
+    Option(SelectOption<'a>),
+    Group(SelectGroup<'a>),
+}
+
+pub struct Select<'a> {
+    mode: SelectMode,
+    size: InputSize,
+    items: Vec<SelectItem<'a>>,
+    placeholder: Option<&'a str>,
+    value: Option<&'a str>,
+    id: Option<&'a str>,
+    name: Option<&'a str>,
+    class: Option<&'a str>,
+    disabled: bool,
+    required: bool,
+    aria_describedby: Option<&'a str>,
+    invalid: bool,
+}
+
+impl<'a> Default for Select<'a> {
+    fn default() -> Self {
+        Self {
+            mode: SelectMode::Native,
+            size: InputSize::Default,
+            items: Vec::new(),
+            placeholder: None,
+            value: None,
+            id: None,
+            name: None,
+            class: None,
+            disabled: false,
+            required: false,
+            aria_describedby: None,
+            invalid: false,
+        }
+    }
+}
+
+impl<'a> Select<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn listbox(id: &'a str) -> Self {
+        Self::default().mode(SelectMode::Listbox).id(id)
+    }
+
+    pub fn mode(mut self, mode: SelectMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    pub fn size(mut self, size: InputSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn option(mut self, option: SelectOption<'a>) -> Self {
+        self.items.push(SelectItem::Option(option));
+        self
+    }
+
+    pub fn group(mut self, group: SelectGroup<'a>) -> Self {
+        self.items.push(SelectItem::Group(group));
+        self
+    }
+
+    pub fn placeholder(mut self, placeholder: &'a str) -> Self {
+        self.placeholder = Some(placeholder);
+        self
+    }
+
+    /// Value of the selected option.
+    pub fn value(mut self, value: &'a str) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    /// Defaults to `name`.
+    pub fn id(mut self, id: &'a str) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    pub fn name(mut self, name: &'a str) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    pub fn class(mut self, class: &'a str) -> Self {
+        self.class = Some(class);
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    pub fn required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+
+    pub fn aria_describedby(mut self, aria_describedby: &'a str) -> Self {
+        self.aria_describedby = Some(aria_describedby);
+        self
+    }
+
+    /// Render the control in its error state (`aria-invalid` and destructive colors).
+    pub fn invalid(mut self, invalid: bool) -> Self {
+        self.invalid = invalid;
+        self
+    }
+
+    pub fn build(self) -> Markup {
+        match self.mode {
+            SelectMode::Native => native_select(self),
+            SelectMode::Listbox => listbox_select(self),
+        }
+    }
+}
+
+fn native_select(props: Select) -> Markup {
+    let base_classes = "flex w-full rounded-md border border-input bg-background text-foreground px-3 py-2 text-sm ring-offset-background focus-visible:outline-none focus-visible:ring-2 focus-visible:ring-ring focus-visible:ring-offset-2 disabled:cursor-not-allowed disabled:opacity-50 aria-[invalid=true]:border-destructive aria-[invalid=true]:focus-visible:ring-destructive";
+    let classes = with_class(
+        &format!("{} {}", base_classes, props.size.classes()),
+        props.class,
+    );
+
+    let native_option = |option: &SelectOption| {
+        html! {
+            option
+                value=(option.value)
+                selected[props.value == Some(option.value)]
+                disabled[option.disabled]
+            {
+                (option.label)
+            }
+        }
+    };
+
+    html! {
+        select
+            class=(classes)
+            id=[props.id.or(props.name)]
+            name=[props.name]
+            disabled[props.disabled]
+            aria-describedby=[props.aria_describedby]
+            aria-invalid=[props.invalid.then_some("true")]
+            required[props.required]
+        {
+            @if let Some(placeholder) = props.placeholder {
+                option value="" disabled selected[props.value.is_none()] hidden {
+                    (placeholder)
+                }
+            }
+            @for item in &props.items {
+                @match item {
+                    SelectItem::Option(option) => (native_option(option)),
+                    SelectItem::Group(group) => {
+                        optgroup label=(group.label) {
+                            @for option in &group.options {
+                                (native_option(option))
+                            }
+                        }
+                    },
+                }
+            }
+        }
+    }
+}
+
+fn listbox_select(props: Select) -> Markup {
+    let trigger_classes = "flex w-full items-center justify-between rounded-md border border-input bg-background px-3 py-2 text-sm ring-offset-background focus:outline-none focus:ring-2 focus:ring-ring focus:ring-offset-2 disabled:cursor-not-allowed disabled:opacity-50 aria-[invalid=true]:border-destructive aria-[invalid=true]:focus:ring-destructive [&>span]:line-clamp-1";
+    let listbox_classes = "absolute z-50 mt-1 max-h-96 w-full min-w-[8rem] overflow-auto rounded-md border border-border bg-popover p-1 text-popover-foreground shadow-md";
+    let option_classes = "group relative flex w-full cursor-default select-none items-center rounded-sm py-1.5 pl-8 pr-2 text-sm outline-none hover:bg-accent hover:text-accent-foreground data-[highlighted]:bg-accent data-[highlighted]:text-accent-foreground aria-disabled:pointer-events-none aria-disabled:opacity-50";
+
+    let id = props
+        .id
+        .or(props.name)
+        .expect("listbox Select needs an id or a name");
+    let listbox_id = format!("{}-listbox", id);
+    let classes = with_class(
+        &format!("{} {}", trigger_classes, props.size.classes()),
+        props.class,
+    );
+
+    let selected_label = props
+        .items
+        .iter()
+        .flat_map(|item| match item {
+            SelectItem::Option(option) => std::slice::from_ref(option),
+            SelectItem::Group(group) => &group.options[..],
+        })
+        .find(|option| props.value == Some(option.value))
+        .map(|option| option.label);
+
+    let mut index = 0;
+    let mut listbox_option = |option: &SelectOption| {
+        index += 1;
+        html! {
+            div
+                class=(option_classes)
+                role="option"
+                id=(format!("{}-option-{}", id, index))
+                data-value=(option.value)
+                aria-selected=(props.value == Some(option.value))
+                aria-disabled=[option.disabled.then_some("true")]
+            {
+                span class="invisible absolute left-2 flex h-3.5 w-3.5 items-center justify-center group-aria-selected:visible" {
+                    svg class="h-4 w-4" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round" viewBox="0 0 24 24" xmlns="http://www.w3.org/2000/svg" {
+                        path d="M20 6 9 17l-5-5" {}
+                    }
+                }
+                (option.label)
+            }
+        }
+    };
+
+    html! {
+        div class="relative w-full" data-select {
+            // Hidden inputs are skipped by constraint validation, a visually
+            // hidden text input is used instead when a value is required.
+            input
+                class="sr-only"
+                type=(if props.required { "text" } else { "hidden" })
+                name=[props.name]
+                value=(props.value.unwrap_or(""))
+                tabindex="-1"
+                aria-hidden="true"
+                disabled[props.disabled]
+                required[props.required]
+                data-select-input {}
+            button
+                class=(classes)
+                type="button"
+                role="combobox"
+                id=(id)
+                aria-haspopup="listbox"
+                aria-expanded="false"
+                aria-controls=(listbox_id)
+                aria-required=[props.required.then_some("true")]
+                aria-describedby=[props.aria_describedby]
+                aria-invalid=[props.invalid.then_some("true")]
+                disabled[props.disabled]
+            {
+                span
+                    class="data-[placeholder]:text-muted-foreground"
+                    data-placeholder[selected_label.is_none()]
+                    data-select-value
+                {
+                    (selected_label.or(props.placeholder).unwrap_or(""))
+                }
+                svg class="h-4 w-4 opacity-50" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round" viewBox="0 0 24 24" xmlns="http://www.w3.org/2000/svg" {
+                    path d="m6 9 6 6 6-6" {}
+                }
+            }
+            div class=(listbox_classes) role="listbox" id=(listbox_id) tabindex="-1" hidden {
+                @for (group_index, item) in props.items.iter().enumerate() {
+                    @match item {
+                        SelectItem::Option(option) => (listbox_option(option)),
+                        SelectItem::Group(group) => {
+                            @let label_id = format!("{}-group-{}", id, group_index);
+                            div role="group" aria-labelledby=(label_id) {
+                                div class="py-1.5 pl-8 pr-2 text-sm font-semibold" id=(label_id) {
+                                    (group.label)
+                                }
+                                @for option in &group.options {
+                                    (listbox_option(option))
+                                }
+                            }
+                        },
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn ui_select_script() -> Markup {
+    html! {
+        script {
+            (PreEscaped(include_str!("../../scripts/select.js")))
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use maud::{html, Markup};
+
+use super::{
+    dialog::{close_button, footer, header, modal_script, open_and_load_script},
+    with_class,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum SheetSide {
+    Top,
+    #[default]
+    Right,
+    Bottom,
+    Left,
+}
+
+/// Panel sliding in from a side of the screen, e.g. to edit a record or for
+/// the navigation on mobile.
+///
+/// Like [`Dialog`](super::dialog::Dialog) it is a modal `<dialog>`, closed on
+/// Escape or on a click on the overlay. When `hx_get` is set, the body is
+/// (re)loaded from the server every time the sheet is opened.
+pub struct Sheet<'a> {
+    id: &'a str,
+    side: SheetSide,
+    trigger: Option<Markup>,
+    title: Option<&'a str>,
+    description: Option<&'a str>,
+    footer: Option<Markup>,
+    hx_get: Option<&'a str>,
+    open: bool,
+    close_button: bool,
+    class: Option<&'a str>,
+}
+
+impl<'a> Sheet<'a> {
+    pub fn new(id: &'a str) -> Self {
+        Self {
+            id,
+            side: SheetSide::Right,
+            trigger: None,
+            title: None,
+            description: None,
+            footer: None,
+            hx_get: None,
+            open: false,
+            close_button: true,
+            class: None,
+        }
+    }
+
+    pub fn side(mut self, side: SheetSide) -> Self {
+        self.side = side;
+        self
+    }
+
+    /// Element that opens the sheet when clicked, usually a [`Button`](super::button::Button).
+    pub fn trigger(mut self, trigger: Markup) -> Self {
+        self.trigger = Some(trigger);
+        self
+    }
+
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    pub fn description(mut self, description: &'a str) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    pub fn footer(mut self, footer: Markup) -> Self {
+        self.footer = Some(footer);
+        self
+    }
+
+    pub fn hx_get(mut self, hx_get: &'a str) -> Self {
+        self.hx_get = Some(hx_get);
+        self
+    }
+
+    /// Open the sheet as soon as it is rendered, e.g. when it is swapped in by htmx.
+    pub fn open(mut self, open: bool) -> Self {
+        self.open = open;
+        self
+    }
+
+    pub fn close_button(mut self, close_button: bool) -> Self {
+        self.close_button = close_button;
+        self
+    }
+
+    pub fn class(mut self, class: &'a str) -> Self {
+        self.class = Some(class);
+        self
+    }
+
+    pub fn build(self, content: Markup) -> Markup {
+        sheet(self, content)
+    }
+}
+
+fn sheet(props: Sheet, content: Markup) -> Markup {
+    // Overrides the centering and size limits of the `<dialog>` default styles.
+    let base_classes = "fixed m-0 max-h-none max-w-none border-border bg-background p-0 text-foreground shadow-lg backdrop:bg-black/80";
+    let side_classes = match props.side {
+        SheetSide::Top => "inset-x-0 top-0 bottom-auto w-full border-b open:animate-sheet-in-top",
+        SheetSide::Right => "inset-y-0 right-0 left-auto h-full w-3/4 border-l sm:max-w-sm open:animate-sheet-in-right",
+        SheetSide::Bottom => "inset-x-0 bottom-0 top-auto w-full border-t open:animate-sheet-in-bottom",
+        SheetSide::Left => "inset-y-0 left-0 right-auto h-full w-3/4 border-r sm:max-w-sm open:animate-sheet-in-left",
+    };
+    let classes = with_class(&format!("{} {}", base_classes, side_classes), props.class);
+
+    let title_id = format!("{}-title", props.id);
+    let description_id = format!("{}-description", props.id);
+    let body_id = format!("{}-body", props.id);
+
+    html! {
+        @if let Some(trigger) = props.trigger {
+            span class="contents" _=(open_and_load_script(props.id, props.hx_get)) {
+                (trigger)
+            }
+        }
+        dialog
+            id=(props.id)
+            class=(classes)
+            role="dialog"
+            aria-modal="true"
+            aria-labelledby=[props.title.map(|_| &title_id)]
+            aria-describedby=[props.description.map(|_| &description_id)]
+            _=(modal_script(props.open))
+        {
+            div class="relative flex h-full flex-col gap-4 p-6" {
+                @if props.title.is_some() || props.description.is_some() {
+                    (header(&title_id, props.title, &description_id, props.description))
+                }
+                div
+                    class="flex-1 overflow-y-auto"
+                    id=(body_id)
+                    hx-get=[props.hx_get]
+                    hx-trigger=[props.hx_get.map(|_| "dialog:open")]
+                {
+                    (content)
+                }
+                @if let Some(footer_content) = props.footer {
+                    (footer(footer_content))
+                }
+                @if props.close_button {
+                    (close_button(props.id))
+                }
+            }
+        }
+    }
+}
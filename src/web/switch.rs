@@ -0,0 +1,96 @@
+use maud::{html, Markup};
+
+use super::with_class;
+
+/// On/off toggle rendered as a `role="switch"` checkbox, so it submits like one.
+#[derive(Default)]
+pub struct Switch<'a> {
+    id: Option<&'a str>,
+    name: Option<&'a str>,
+    value: Option<&'a str>,
+    class: Option<&'a str>,
+    checked: bool,
+    disabled: bool,
+    required: bool,
+    aria_describedby: Option<&'a str>,
+    invalid: bool,
+}
+
+impl<'a> Switch<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn id(mut self, id: &'a str) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    pub fn name(mut self, name: &'a str) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    pub fn value(mut self, value: &'a str) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    pub fn class(mut self, class: &'a str) -> Self {
+        self.class = Some(class);
+        self
+    }
+
+    pub fn checked(mut self, checked: bool) -> Self {
+        self.checked = checked;
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    pub fn required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+
+    pub fn aria_describedby(mut self, aria_describedby: &'a str) -> Self {
+        self.aria_describedby = Some(aria_describedby);
+        self
+    }
+
+    /// Render the control in its error state (`aria-invalid` and destructive colors).
+    pub fn invalid(mut self, invalid: bool) -> Self {
+        self.invalid = invalid;
+        self
+    }
+
+    pub fn build(self) -> Markup {
+        switch(self)
+    }
+}
+
+fn switch(props: Switch) -> Markup {
+    let base_classes = "peer h-6 w-11 shrink-0 cursor-pointer appearance-none rounded-full border-2 border-transparent bg-input transition-colors checked:bg-primary focus-visible:outline-none focus-visible:ring-2 focus-visible:ring-ring focus-visible:ring-offset-2 focus-visible:ring-offset-background disabled:cursor-not-allowed disabled:opacity-50 aria-[invalid=true]:ring-2 aria-[invalid=true]:ring-destructive";
+    let classes = with_class(base_classes, props.class);
+
+    html! {
+        span class="relative inline-flex h-6 w-11 shrink-0" {
+            input
+                class=(classes)
+                type="checkbox"
+                role="switch"
+                id=[props.id]
+                name=[props.name]
+                value=[props.value]
+                checked[props.checked]
+                disabled[props.disabled]
+                aria-describedby=[props.aria_describedby]
+                aria-invalid=[props.invalid.then_some("true")]
+                required[props.required] {}
+            span class="pointer-events-none absolute left-0.5 top-0.5 block h-5 w-5 rounded-full bg-background shadow-lg ring-0 transition-transform peer-checked:translate-x-5" {}
+        }
+    }
+}
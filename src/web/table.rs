@@ -0,0 +1,242 @@
+use maud::{html, Markup};
+
+use super::with_class;
+
+#[derive(Default)]
+pub struct Table<'a> {
+    id: Option<&'a str>,
+    class: Option<&'a str>,
+}
+
+impl<'a> Table<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn id(mut self, id: &'a str) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    pub fn class(mut self, class: &'a str) -> Self {
+        self.class = Some(class);
+        self
+    }
+
+    pub fn build(self, content: Markup) -> Markup {
+        let classes = with_class("w-full caption-bottom text-sm", self.class);
+
+        html! {
+            div class="relative w-full overflow-auto" {
+                table class=(classes) id=[self.id] {
+                    (content)
+                }
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct TableHeader<'a> {
+    id: Option<&'a str>,
+    class: Option<&'a str>,
+}
+
+impl<'a> TableHeader<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn id(mut self, id: &'a str) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    pub fn class(mut self, class: &'a str) -> Self {
+        self.class = Some(class);
+        self
+    }
+
+    pub fn build(self, content: Markup) -> Markup {
+        let classes = with_class("[&_tr]:border-b", self.class);
+
+        html! {
+            thead class=(classes) id=[self.id] {
+                (content)
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct TableBody<'a> {
+    id: Option<&'a str>,
+    class: Option<&'a str>,
+}
+
+impl<'a> TableBody<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn id(mut self, id: &'a str) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    pub fn class(mut self, class: &'a str) -> Self {
+        self.class = Some(class);
+        self
+    }
+
+    pub fn build(self, content: Markup) -> Markup {
+        let classes = with_class("[&_tr:last-child]:border-0", self.class);
+
+        html! {
+            tbody class=(classes) id=[self.id] {
+                (content)
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct TableRow<'a> {
+    id: Option<&'a str>,
+    class: Option<&'a str>,
+}
+
+impl<'a> TableRow<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn id(mut self, id: &'a str) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    pub fn class(mut self, class: &'a str) -> Self {
+        self.class = Some(class);
+        self
+    }
+
+    pub fn build(self, content: Markup) -> Markup {
+        let classes = with_class("border-b border-border transition-colors hover:bg-muted/50 data-[state=selected]:bg-muted", self.class);
+
+        html! {
+            tr class=(classes) id=[self.id] {
+                (content)
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct TableHead<'a> {
+    id: Option<&'a str>,
+    class: Option<&'a str>,
+    aria_sort: Option<&'a str>,
+}
+
+impl<'a> TableHead<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn id(mut self, id: &'a str) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    pub fn class(mut self, class: &'a str) -> Self {
+        self.class = Some(class);
+        self
+    }
+
+    /// `ascending`, `descending`, `none` or `other`.
+    pub fn aria_sort(mut self, aria_sort: &'a str) -> Self {
+        self.aria_sort = Some(aria_sort);
+        self
+    }
+
+    pub fn build(self, content: Markup) -> Markup {
+        let classes = with_class("h-12 px-4 text-left align-middle font-medium text-muted-foreground [&:has([role=checkbox])]:pr-0", self.class);
+
+        html! {
+            th class=(classes) id=[self.id] aria-sort=[self.aria_sort] {
+                (content)
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct TableCell<'a> {
+    id: Option<&'a str>,
+    class: Option<&'a str>,
+    colspan: Option<usize>,
+}
+
+impl<'a> TableCell<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn id(mut self, id: &'a str) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    pub fn class(mut self, class: &'a str) -> Self {
+        self.class = Some(class);
+        self
+    }
+
+    pub fn colspan(mut self, colspan: usize) -> Self {
+        self.colspan = Some(colspan);
+        self
+    }
+
+    pub fn build(self, content: Markup) -> Markup {
+        let classes = with_class("p-4 align-middle [&:has([role=checkbox])]:pr-0", self.class);
+
+        html! {
+            td class=(classes) id=[self.id] colspan=[self.colspan] {
+                (content)
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct TableCaption<'a> {
+    id: Option<&'a str>,
+    class: Option<&'a str>,
+}
+
+impl<'a> TableCaption<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn id(mut self, id: &'a str) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    pub fn class(mut self, class: &'a str) -> Self {
+        self.class = Some(class);
+        self
+    }
+
+    pub fn build(self, content: Markup) -> Markup {
+        let classes = with_class("mt-4 text-sm text-muted-foreground", self.class);
+
+        html! {
+            caption class=(classes) id=[self.id] {
+                (content)
+            }
+        }
+    }
+}
@@ -0,0 +1,154 @@
+use maud::{html, Markup, PreEscaped};
+
+use super::{
+    button::{button_classes, ButtonSize, ButtonVariant},
+    with_class,
+};
+
+pub struct Tab<'a> {
+    value: &'a str,
+    label: &'a str,
+    content: Option<Markup>,
+    hx_get: Option<&'a str>,
+    disabled: bool,
+}
+
+impl<'a> Tab<'a> {
+    pub fn new(value: &'a str, label: &'a str) -> Self {
+        Self {
+            value,
+            label,
+            content: None,
+            hx_get: None,
+            disabled: false,
+        }
+    }
+
+    /// Panel content rendered inline. With `hx_get` it is shown until the panel is loaded.
+    pub fn content(mut self, content: Markup) -> Self {
+        self.content = Some(content);
+        self
+    }
+
+    /// Load the panel from the server the first time the tab is activated.
+    pub fn hx_get(mut self, hx_get: &'a str) -> Self {
+        self.hx_get = Some(hx_get);
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+/// Tabs following the WAI-ARIA tabs pattern.
+///
+/// Requires [`ui_tabs_script`] on the page for click and arrow-key handling.
+pub struct Tabs<'a> {
+    id: &'a str,
+    tabs: Vec<Tab<'a>>,
+    active: Option<&'a str>,
+    class: Option<&'a str>,
+}
+
+impl<'a> Tabs<'a> {
+    pub fn new(id: &'a str) -> Self {
+        Self {
+            id,
+            tabs: Vec::new(),
+            active: None,
+            class: None,
+        }
+    }
+
+    pub fn tab(mut self, tab: Tab<'a>) -> Self {
+        self.tabs.push(tab);
+        self
+    }
+
+    /// Value of the initially active tab, defaults to the first one.
+    pub fn active(mut self, active: &'a str) -> Self {
+        self.active = Some(active);
+        self
+    }
+
+    pub fn class(mut self, class: &'a str) -> Self {
+        self.class = Some(class);
+        self
+    }
+
+    pub fn build(self) -> Markup {
+        tabs(self)
+    }
+}
+
+fn tabs(props: Tabs) -> Markup {
+    let list_classes =
+        "inline-flex items-center justify-center rounded-md bg-muted p-1 text-muted-foreground";
+    let trigger_classes = format!(
+        "{} whitespace-nowrap data-[state=active]:bg-background data-[state=active]:text-foreground data-[state=active]:shadow-sm",
+        button_classes(&ButtonVariant::Ghost, &ButtonSize::Sm)
+    );
+    let panel_classes = "mt-2 ring-offset-background focus-visible:outline-none focus-visible:ring-2 focus-visible:ring-ring focus-visible:ring-offset-2";
+
+    let active = props
+        .active
+        .or_else(|| props.tabs.first().map(|tab| tab.value));
+
+    html! {
+        div id=(props.id) class=(with_class("w-full", props.class)) data-tabs {
+            div class=(list_classes) role="tablist" aria-orientation="horizontal" {
+                @for tab in &props.tabs {
+                    @let selected = Some(tab.value) == active;
+                    button
+                        class=(trigger_classes)
+                        type="button"
+                        role="tab"
+                        id=(format!("{}-tab-{}", props.id, tab.value))
+                        aria-controls=(format!("{}-panel-{}", props.id, tab.value))
+                        aria-selected=(selected)
+                        tabindex=(if selected { "0" } else { "-1" })
+                        data-state=(if selected { "active" } else { "inactive" })
+                        disabled[tab.disabled]
+                    {
+                        (tab.label)
+                    }
+                }
+            }
+            @for tab in props.tabs {
+                @let selected = Some(tab.value) == active;
+                @let hx_trigger = match (selected, tab.content.is_some()) {
+                    // The server already rendered the active panel, fetching
+                    // it again would replace what the user is working on.
+                    (true, true) => None,
+                    (true, false) => Some("load"),
+                    (false, _) => Some("tabs:activate once"),
+                };
+                div
+                    class=(panel_classes)
+                    role="tabpanel"
+                    id=(format!("{}-panel-{}", props.id, tab.value))
+                    aria-labelledby=(format!("{}-tab-{}", props.id, tab.value))
+                    tabindex="0"
+                    data-state=(if selected { "active" } else { "inactive" })
+                    hidden[!selected]
+                    hx-get=[hx_trigger.and(tab.hx_get)]
+                    hx-trigger=[tab.hx_get.and(hx_trigger)]
+                {
+                    @if let Some(content) = tab.content {
+                        (content)
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn ui_tabs_script() -> Markup {
+    html! {
+        script {
+            (PreEscaped(include_str!("../../scripts/tabs.js")))
+        }
+    }
+}
@@ -0,0 +1,171 @@
+use maud::{html, Markup};
+
+use super::{input::INPUT_CLASSES, with_class};
+
+#[derive(Default)]
+pub struct Textarea<'a> {
+    placeholder: Option<&'a str>,
+    value: Option<&'a str>,
+    id: Option<&'a str>,
+    name: Option<&'a str>,
+    class: Option<&'a str>,
+    rows: Option<usize>,
+    max_length: Option<usize>,
+    auto_grow: bool,
+    show_count: bool,
+    disabled: bool,
+    required: bool,
+    aria_describedby: Option<&'a str>,
+    invalid: bool,
+}
+
+impl<'a> Textarea<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn placeholder(mut self, placeholder: &'a str) -> Self {
+        self.placeholder = Some(placeholder);
+        self
+    }
+
+    pub fn value(mut self, value: &'a str) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    pub fn id(mut self, id: &'a str) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    pub fn name(mut self, name: &'a str) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    pub fn class(mut self, class: &'a str) -> Self {
+        self.class = Some(class);
+        self
+    }
+
+    pub fn rows(mut self, rows: usize) -> Self {
+        self.rows = Some(rows);
+        self
+    }
+
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Grow the textarea with its content instead of scrolling.
+    pub fn auto_grow(mut self, auto_grow: bool) -> Self {
+        self.auto_grow = auto_grow;
+        self
+    }
+
+    /// Show a live character count below the textarea (out of `max_length` if set).
+    /// With an `id` (or `name`) the count, `{id}-count`, is added to `aria-describedby`.
+    pub fn show_count(mut self, show_count: bool) -> Self {
+        self.show_count = show_count;
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    pub fn required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+
+    pub fn aria_describedby(mut self, aria_describedby: &'a str) -> Self {
+        self.aria_describedby = Some(aria_describedby);
+        self
+    }
+
+    /// Render the control in its error state (`aria-invalid` and destructive colors).
+    pub fn invalid(mut self, invalid: bool) -> Self {
+        self.invalid = invalid;
+        self
+    }
+
+    pub fn build(self) -> Markup {
+        textarea(self)
+    }
+}
+
+fn textarea(props: Textarea) -> Markup {
+    let mut classes = with_class(&format!("{} min-h-[80px]", INPUT_CLASSES), props.class);
+    if props.auto_grow {
+        classes.push_str(" resize-none overflow-hidden");
+    }
+
+    let max_suffix = props
+        .max_length
+        .map(|max| format!(" / {}", max))
+        .unwrap_or_default();
+
+    let mut hyperscript = Vec::new();
+    if props.auto_grow {
+        hyperscript.push(
+            "init set my.style.height to my.scrollHeight + 'px' end \
+             on input set my.style.height to 'auto' then set my.style.height to my.scrollHeight + 'px' end"
+                .to_string(),
+        );
+    }
+    if props.show_count {
+        hyperscript.push(format!(
+            "on input put my.value.length + '{}' into the next <p/> end",
+            max_suffix
+        ));
+    }
+
+    // The counter is part of the description of the textarea.
+    let count_id = props
+        .show_count
+        .then(|| props.id.or(props.name).map(|id| format!("{}-count", id)))
+        .flatten();
+    let describedby = [props.aria_describedby, count_id.as_deref()]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let textarea = html! {
+        textarea
+            class=(classes)
+            placeholder=[props.placeholder]
+            id=[props.id]
+            name=[props.name]
+            rows=[props.rows]
+            maxlength=[props.max_length]
+            disabled[props.disabled]
+            aria-describedby=[(!describedby.is_empty()).then_some(&describedby)]
+            aria-invalid=[props.invalid.then_some("true")]
+            required[props.required]
+            _=[(!hyperscript.is_empty()).then(|| hyperscript.join(" "))]
+        {
+            (props.value.unwrap_or(""))
+        }
+    };
+
+    if !props.show_count {
+        return textarea;
+    }
+
+    // Browsers count UTF-16 code units for both `maxlength` and `value.length`.
+    let count = props.value.map_or(0, |value| value.encode_utf16().count());
+
+    html! {
+        div class="grid w-full gap-1.5" {
+            (textarea)
+            p class="text-right text-xs text-muted-foreground" id=[count_id] {
+                (count) (max_suffix)
+            }
+        }
+    }
+}
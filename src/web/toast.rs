@@ -0,0 +1,309 @@
+use std::convert::Infallible;
+
+use axum::{
+    http::HeaderValue,
+    response::{IntoResponseParts, ResponseParts},
+};
+use maud::{html, Markup, PreEscaped};
+use serde::Serialize;
+
+use super::with_class;
+
+/// Id of the [`Toaster`] region toasts are appended to.
+pub const TOASTER_ID: &str = "toaster";
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ToastVariant {
+    #[default]
+    Default,
+    Destructive,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ToastAction {
+    label: String,
+    hx_post: String,
+}
+
+/// Notification shown in the [`Toaster`].
+///
+/// A handler sends it either as an out-of-band swap with [`Toast::oob`] or in
+/// the `HX-Trigger` response header through [`Toasts`].
+#[derive(Debug, Clone, Serialize)]
+pub struct Toast {
+    title: Option<String>,
+    description: Option<String>,
+    variant: ToastVariant,
+    action: Option<ToastAction>,
+    duration: u64,
+}
+
+impl Default for Toast {
+    fn default() -> Self {
+        Self {
+            title: None,
+            description: None,
+            variant: ToastVariant::Default,
+            action: None,
+            duration: 5000,
+        }
+    }
+}
+
+impl Toast {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn destructive() -> Self {
+        Self::default().variant(ToastVariant::Destructive)
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn variant(mut self, variant: ToastVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    /// Button sending an `hx-post` to `hx_post` (e.g. an "Undo" endpoint).
+    pub fn action(mut self, label: impl Into<String>, hx_post: impl Into<String>) -> Self {
+        self.action = Some(ToastAction {
+            label: label.into(),
+            hx_post: hx_post.into(),
+        });
+        self
+    }
+
+    /// Milliseconds before the toast is dismissed, `0` keeps it until closed.
+    pub fn duration(mut self, duration: u64) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    pub fn build(&self) -> Markup {
+        toast(self)
+    }
+
+    /// The toast as an out-of-band swap appending it to the [`Toaster`],
+    /// to be added to an htmx response body.
+    pub fn oob(&self) -> Markup {
+        html! {
+            div hx-swap-oob=(format!("beforeend:#{}", TOASTER_ID)) {
+                (self.build())
+            }
+        }
+    }
+}
+
+fn toast(props: &Toast) -> Markup {
+    let base_classes = "group pointer-events-auto relative flex w-full items-center justify-between space-x-4 overflow-hidden rounded-md border p-6 pr-8 shadow-lg transition-all";
+    let variant_classes = match props.variant {
+        ToastVariant::Default => "border-border bg-background text-foreground",
+        ToastVariant::Destructive => {
+            "destructive border-destructive bg-destructive text-destructive-foreground"
+        }
+    };
+    let action_classes = "inline-flex h-8 shrink-0 items-center justify-center rounded-md border border-border bg-transparent px-3 text-sm font-medium ring-offset-background transition-colors hover:bg-secondary focus:outline-none focus:ring-2 focus:ring-ring focus:ring-offset-2 disabled:pointer-events-none disabled:opacity-50 group-[.destructive]:border-muted/40 group-[.destructive]:hover:border-destructive/30 group-[.destructive]:hover:bg-destructive group-[.destructive]:hover:text-destructive-foreground group-[.destructive]:focus:ring-destructive";
+    let close_classes = "absolute right-2 top-2 rounded-md p-1 text-foreground/50 opacity-0 transition-opacity hover:text-foreground focus:opacity-100 focus:outline-none focus:ring-2 group-hover:opacity-100 group-[.destructive]:text-red-300 group-[.destructive]:hover:text-red-50 group-[.destructive]:focus:ring-red-400 group-[.destructive]:focus:ring-offset-red-600";
+
+    let hyperscript = (props.duration > 0).then(|| dismiss_script(props.duration));
+
+    // Empty slots are kept (hidden) so the markup can serve as template for
+    // the toasts delivered through `HX-Trigger`. There is no `role="status"`,
+    // the `aria-live` region of the `Toaster` already announces the toast.
+    html! {
+        li
+            class=(with_class(base_classes, Some(variant_classes)))
+            data-toast
+            _=[hyperscript]
+        {
+            div class="grid gap-1" {
+                div class="text-sm font-semibold" hidden[props.title.is_none()] data-toast-title {
+                    (props.title.as_deref().unwrap_or_default())
+                }
+                div class="text-sm opacity-90" hidden[props.description.is_none()] data-toast-description {
+                    (props.description.as_deref().unwrap_or_default())
+                }
+            }
+            button
+                class=(action_classes)
+                type="button"
+                hidden[props.action.is_none()]
+                hx-post=[props.action.as_ref().map(|action| &action.hx_post)]
+                hx-swap="none"
+                data-toast-action
+                _="on click remove closest <li/>"
+            {
+                (props.action.as_ref().map(|action| action.label.as_str()).unwrap_or_default())
+            }
+            button class=(close_classes) type="button" aria-label="Close" _="on click remove closest <li/>" {
+                svg class="h-4 w-4" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round" viewBox="0 0 24 24" xmlns="http://www.w3.org/2000/svg" {
+                    path d="M18 6 6 18" {}
+                    path d="m6 6 12 12" {}
+                }
+            }
+        }
+    }
+}
+
+fn dismiss_script(duration: u64) -> String {
+    format!("init wait {}ms then remove me", duration)
+}
+
+/// Toasts sent in the `HX-Trigger` response header, rendered client-side by
+/// [`ui_toaster_script`].
+///
+/// ```ignore
+/// async fn save() -> impl IntoResponse {
+///     (Toasts::new().push(Toast::new().title("Saved")), html! { ... })
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Toasts {
+    toasts: Vec<Toast>,
+}
+
+impl Toasts {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(mut self, toast: Toast) -> Self {
+        self.toasts.push(toast);
+        self
+    }
+}
+
+#[derive(Serialize)]
+struct ToastTrigger<'a> {
+    toast: ToastTriggerDetail<'a>,
+}
+
+#[derive(Serialize)]
+struct ToastTriggerDetail<'a> {
+    toasts: &'a [Toast],
+}
+
+impl IntoResponseParts for Toasts {
+    type Error = Infallible;
+
+    fn into_response_parts(self, mut res: ResponseParts) -> Result<ResponseParts, Self::Error> {
+        if self.toasts.is_empty() {
+            return Ok(res);
+        }
+
+        let trigger = ToastTrigger {
+            toast: ToastTriggerDetail {
+                toasts: &self.toasts,
+            },
+        };
+        let json = serde_json::to_string(&trigger).expect("serialize toasts");
+
+        res.headers_mut().insert(
+            "HX-Trigger",
+            HeaderValue::from_str(&escape_header_json(&json)).expect("visible ascii header value"),
+        );
+        Ok(res)
+    }
+}
+
+/// Escape the characters of `json` header values can't contain (anything but
+/// visible ASCII and space) as JSON `\uXXXX` sequences. serde_json only
+/// escapes the control characters below 0x20, and those only occur in strings.
+fn escape_header_json(json: &str) -> String {
+    let mut value = String::with_capacity(json.len());
+    for c in json.chars() {
+        if matches!(c, ' '..='~') {
+            value.push(c);
+        } else {
+            for unit in c.encode_utf16(&mut [0; 2]) {
+                value.push_str(&format!("\\u{:04x}", unit));
+            }
+        }
+    }
+    value
+}
+
+/// Region where toasts are shown, to be rendered once per page.
+#[derive(Default)]
+pub struct Toaster<'a> {
+    class: Option<&'a str>,
+}
+
+impl<'a> Toaster<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn class(mut self, class: &'a str) -> Self {
+        self.class = Some(class);
+        self
+    }
+
+    pub fn build(self) -> Markup {
+        toaster(self)
+    }
+}
+
+fn toaster(props: Toaster) -> Markup {
+    let base_classes = "fixed bottom-0 right-0 z-[100] flex max-h-screen w-full flex-col gap-2 p-4 md:max-w-[420px]";
+
+    html! {
+        ol
+            id=(TOASTER_ID)
+            class=(with_class(base_classes, props.class))
+            role="region"
+            aria-label="Notifications"
+            aria-live="polite"
+            tabindex="-1"
+            data-toaster {}
+        template data-toast-template="default" {
+            (Toast::new().duration(0).build())
+        }
+        template data-toast-template="destructive" {
+            (Toast::destructive().duration(0).build())
+        }
+    }
+}
+
+pub fn ui_toaster_script() -> Markup {
+    html! {
+        script {
+            (PreEscaped(include_str!("../../scripts/toaster.js")))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::HeaderValue;
+
+    use super::escape_header_json;
+
+    #[test]
+    fn escapes_non_ascii_and_control_characters() {
+        let text = "caf\u{e9} \u{1f389} tab\t del\u{7f} nul\u{0}";
+        let json = serde_json::to_string(text).unwrap();
+        let value = escape_header_json(&json);
+
+        assert!(value.bytes().all(|byte| (0x20..=0x7e).contains(&byte)));
+        assert!(HeaderValue::from_str(&value).is_ok());
+        assert_eq!(serde_json::from_str::<String>(&value).unwrap(), text);
+    }
+
+    #[test]
+    fn keeps_visible_ascii() {
+        let json = r#"{"toast":{"toasts":[{"title":"Saved ~ 100%"}]}}"#;
+        assert_eq!(escape_header_json(json), json);
+    }
+}
@@ -0,0 +1,100 @@
+use maud::{html, Markup, PreEscaped};
+
+use super::{
+    floating::{Align, Placement, Side},
+    with_class,
+};
+
+/// Text shown when the `trigger` passed to `build` is hovered or focused.
+///
+/// The tooltip is linked to the trigger with `aria-describedby` while shown,
+/// unless it only repeats the trigger `aria-label`. Requires
+/// [`ui_tooltip_script`] and
+/// [`ui_floating_script`](super::floating::ui_floating_script) on the page.
+pub struct Tooltip<'a> {
+    content: &'a str,
+    placement: Placement,
+    delay: u32,
+    id: Option<&'a str>,
+    class: Option<&'a str>,
+}
+
+impl<'a> Tooltip<'a> {
+    pub fn new(content: &'a str) -> Self {
+        Self {
+            content,
+            placement: Placement::new(Side::Top, Align::Center, 4),
+            delay: 700,
+            id: None,
+            class: None,
+        }
+    }
+
+    pub fn side(mut self, side: Side) -> Self {
+        self.placement.side = side;
+        self
+    }
+
+    pub fn align(mut self, align: Align) -> Self {
+        self.placement.align = align;
+        self
+    }
+
+    /// Distance from the trigger in pixels.
+    pub fn side_offset(mut self, side_offset: u32) -> Self {
+        self.placement.side_offset = side_offset;
+        self
+    }
+
+    /// Milliseconds the pointer has to rest on the trigger before the tooltip
+    /// is shown. Focusing the trigger shows it right away.
+    pub fn delay(mut self, delay: u32) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Id of the tooltip, one is assigned on the client otherwise.
+    pub fn id(mut self, id: &'a str) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    pub fn class(mut self, class: &'a str) -> Self {
+        self.class = Some(class);
+        self
+    }
+
+    pub fn build(self, trigger: Markup) -> Markup {
+        tooltip(self, trigger)
+    }
+}
+
+fn tooltip(props: Tooltip, trigger: Markup) -> Markup {
+    let base_classes = "z-50 overflow-hidden rounded-md border border-border bg-popover px-3 py-1.5 text-sm text-popover-foreground shadow-md";
+
+    html! {
+        span class="inline-flex" data-tooltip data-tooltip-delay=(props.delay) {
+            (trigger)
+            div
+                class=(with_class(base_classes, props.class))
+                role="tooltip"
+                id=[props.id]
+                hidden
+                data-floating
+                data-floating-side=(props.placement.side.as_str())
+                data-floating-align=(props.placement.align.as_str())
+                data-floating-offset=(props.placement.side_offset)
+            {
+                (props.content)
+            }
+        }
+    }
+}
+
+pub fn ui_tooltip_script() -> Markup {
+    html! {
+        script {
+            (PreEscaped(include_str!("../../scripts/tooltip.js")))
+        }
+    }
+}
@@ -60,10 +60,26 @@ module.exports = {
                     from: { height: "var(--radix-accordion-content-height)" },
                     to: { height: "0" },
                 },
+                "sheet-in-top": {
+                    from: { transform: "translateY(-100%)" },
+                },
+                "sheet-in-right": {
+                    from: { transform: "translateX(100%)" },
+                },
+                "sheet-in-bottom": {
+                    from: { transform: "translateY(100%)" },
+                },
+                "sheet-in-left": {
+                    from: { transform: "translateX(-100%)" },
+                },
             },
             animation: {
                 "accordion-down": "accordion-down 0.2s ease-out",
                 "accordion-up": "accordion-up 0.2s ease-out",
+                "sheet-in-top": "sheet-in-top 0.3s ease-out",
+                "sheet-in-right": "sheet-in-right 0.3s ease-out",
+                "sheet-in-bottom": "sheet-in-bottom 0.3s ease-out",
+                "sheet-in-left": "sheet-in-left 0.3s ease-out",
             },
         },
     },